@@ -49,21 +49,38 @@ pub trait CodePointInterface:
 	/// U+0000 NULL à U+007F DELETE, inclusivement.
 	fn is_ascii_code_point(&self) -> bool;
 
-	/// ident-start code point
+	/// XID_Start
 	///
-	/// Une lettre, un point de code non ASCII ou U+005F LOW LINE (_).
+	/// Un point de code pouvant commencer un identifiant selon l'annexe
+	/// UAX #31 d'Unicode : une lettre, de n'importe quelle écriture.
+	fn is_xid_start(&self) -> bool;
+
+	/// XID_Continue
+	///
+	/// Un point de code pouvant suivre le début d'un identifiant selon
+	/// l'annexe UAX #31 d'Unicode : une lettre, un chiffre, une marque
+	/// combinante ou un connecteur comme U+005F LOW LINE (_).
+	fn is_xid_continue(&self) -> bool;
+
+	/// Début d'identifiant
+	///
+	/// Un point de code `XID_Start` ou U+005F LOW LINE (_).
 	fn is_ident_start(&self) -> bool;
 
-	/// ident-after-start code point
+	/// Suite d'identifiant
 	///
-	/// Un caractère alphanumérique, un point de code non ASCII ou U+005F LOW
-	/// LINE (_).
+	/// Un point de code `XID_Continue`.
 	fn is_ident_after_start(&self) -> bool;
 
-	/// ident code point
+	/// ident-start code point (CSS)
+	///
+	/// Une lettre, un point de code non ASCII ou U+005F LOW LINE (_).
+	fn is_css_ident_start(&self) -> bool;
+
+	/// ident code point (CSS)
 	///
-	/// Un point de code ident de début, un chiffre ou U+002D HYPHEN-MINUS
-	/// (-).
+	/// Un point de code ident de début CSS, un chiffre ou U+002D
+	/// HYPHEN-MINUS (-).
 	fn is_ident(&self) -> bool;
 
 	/// newline
@@ -157,16 +174,37 @@ macro_rules! impl_cdi {
 			matches!(self.as_char(), '\0'..='\u{007F}')
 		}
 
+		fn is_xid_start(&self) -> bool {
+			if self.is_ascii_code_point() {
+				return self.is_letter();
+			}
+			$crate::tables::contains($crate::tables::XID_START, self.as_char())
+		}
+
+		fn is_xid_continue(&self) -> bool {
+			if self.is_ascii_code_point() {
+				return self.is_ascii_alphanumeric() || self.is('_');
+			}
+			$crate::tables::contains(
+				$crate::tables::XID_CONTINUE,
+				self.as_char(),
+			)
+		}
+
 		fn is_ident_start(&self) -> bool {
-			self.is_letter() || self.is_non_ascii() || self.is('_')
+			self.is_xid_start() || self.is('_')
 		}
 
 		fn is_ident_after_start(&self) -> bool {
-			self.is_ascii_alphanumeric() || self.is_non_ascii() || self.is('_')
+			self.is_xid_continue()
+		}
+
+		fn is_css_ident_start(&self) -> bool {
+			self.is_letter() || self.is_non_ascii() || self.is('_')
 		}
 
 		fn is_ident(&self) -> bool {
-			self.is_ident_start() || self.is_ascii_digit() || self.is('-')
+			self.is_css_ident_start() || self.is_ascii_digit() || self.is('-')
 		}
 
 		fn is_newline(&self) -> bool {
@@ -221,3 +259,57 @@ macro_rules! impl_cdi {
 // -------------- //
 
 impl_cdi! { char | u8 }
+
+// ---- //
+// Test //
+// ---- //
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::UNICODE_VERSION;
+
+	#[test]
+	fn test_xid_start() {
+		assert_eq!(UNICODE_VERSION, (16, 0, 0));
+
+		for ch in ['a', 'Z', '\u{AA}', '\u{C0}', '\u{376}', '\u{37B}', 'é'] {
+			assert!(ch.is_xid_start(), "{ch:?} est XID_Start");
+		}
+		// NOTE: U+037A GREEK YPOGEGRAMMENI est ID_Start mais pas XID_Start,
+		// sa forme NFKC commence par un espace.
+		for ch in ['_', '0', '\u{A9}', '\u{B7}', '\u{300}', '\u{37A}', '€'] {
+			assert!(!ch.is_xid_start(), "{ch:?} n'est pas XID_Start");
+		}
+
+		// NOTE: bornes du dernier intervalle, CJK Extension H.
+		assert!('\u{31350}'.is_xid_start());
+		assert!('\u{323AF}'.is_xid_start());
+		assert!(!'\u{3134F}'.is_xid_start());
+		assert!(!'\u{323B0}'.is_xid_start());
+		assert!(!'\u{10FFFF}'.is_xid_start());
+		assert!(b'x'.is_xid_start() && !b'_'.is_xid_start());
+	}
+
+	#[test]
+	fn test_xid_continue() {
+		for ch in ['a', '_', '0', '\u{B7}', '\u{300}', '\u{36F}', '\u{200C}'] {
+			assert!(ch.is_xid_continue(), "{ch:?} est XID_Continue");
+		}
+		for ch in ['-', ' ', '\u{A9}', '\u{37A}', '\u{2E2F}', '\u{200B}'] {
+			assert!(!ch.is_xid_continue(), "{ch:?} n'est pas XID_Continue");
+		}
+
+		// NOTE: les chiffres U+1FBF0..U+1FBF9 continuent un identifiant,
+		// mais ne le commencent pas.
+		assert!('\u{1FBF0}'.is_xid_continue() && !'\u{1FBF0}'.is_xid_start());
+		assert!('\u{1FBF9}'.is_xid_continue());
+		assert!(!'\u{1FBFA}'.is_xid_continue());
+
+		// NOTE: bornes du dernier intervalle, les sélecteurs de variante.
+		assert!('\u{E0100}'.is_xid_continue());
+		assert!('\u{E01EF}'.is_xid_continue());
+		assert!(!'\u{E00FF}'.is_xid_continue());
+		assert!(!'\u{E01F0}'.is_xid_continue());
+	}
+}
@@ -3,8 +3,9 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
 
 mod interface;
+mod tables;
 
-pub use self::interface::CodePointInterface;
+pub use self::{interface::CodePointInterface, tables::UNICODE_VERSION};
 
 // ----- //
 // Macro //
@@ -126,12 +127,24 @@ where
 			.unwrap_or_default()
 	}
 
+	pub fn is_css_ident_start(&self) -> bool {
+		self.maybe_unit()
+			.map(|unit| unit.is_css_ident_start())
+			.unwrap_or_default()
+	}
+
 	pub fn is_ident_after_start(&self) -> bool {
 		self.maybe_unit()
 			.map(|unit| unit.is_ident_after_start())
 			.unwrap_or_default()
 	}
 
+	pub fn is_digit(&self) -> bool {
+		self.maybe_unit()
+			.map(|unit| unit.is_ascii_digit())
+			.unwrap_or_default()
+	}
+
 	pub fn is_newline(&self) -> bool {
 		self.maybe_unit()
 			.map(|unit| unit.is_newline())
@@ -0,0 +1,136 @@
+# This Source Code Form is subject to the terms of the Mozilla Public
+# License, v. 2.0. If a copy of the MPL was not distributed with this
+# file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+"""Génère `codepoints/tables.rs` à partir de `DerivedCoreProperties.txt`.
+
+Le fichier source est celui de la base de données Unicode, par exemple pour
+Unicode 16.0.0 :
+
+    curl -O https://www.unicode.org/Public/16.0.0/ucd/DerivedCoreProperties.txt
+    python3 codepoints/tables.py DerivedCoreProperties.txt > codepoints/tables.rs
+
+La version d'Unicode est lue dans l'en-tête du fichier source.
+"""
+
+import re
+import sys
+
+PROPERTIES = ("XID_Start", "XID_Continue")
+
+# Le nombre d'intervalles par ligne.
+PER_LINE = 3
+
+HEADER = """\
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+// NOTE: tables générées par `codepoints/tables.py` à partir du fichier
+// `DerivedCoreProperties.txt` de la base de données Unicode :
+// https://www.unicode.org/Public/{major}.{minor}.{update}/ucd/DerivedCoreProperties.txt
+//
+//     python3 codepoints/tables.py DerivedCoreProperties.txt \\
+//         > codepoints/tables.rs
+//
+// Ne pas modifier à la main.
+
+// -------- //
+// Constant //
+// -------- //
+
+/// La version d'Unicode des tables.
+pub const UNICODE_VERSION: (u8, u8, u8) = ({major}, {minor}, {update});
+"""
+
+FOOTER = """
+// -------- //
+// Fonction //
+// -------- //
+
+/// Cherche le point de code dans une table d'intervalles triés.
+pub(crate) fn contains(table: &[(char, char)], ch: char) -> bool {
+\ttable
+\t\t.binary_search_by(|&(start, end)| {
+\t\t\tif ch < start {
+\t\t\t\tcore::cmp::Ordering::Greater
+\t\t\t} else if ch > end {
+\t\t\t\tcore::cmp::Ordering::Less
+\t\t\t} else {
+\t\t\t\tcore::cmp::Ordering::Equal
+\t\t\t}
+\t\t})
+\t\t.is_ok()
+}
+"""
+
+
+def parse(lines):
+    """La version d'Unicode, et les intervalles de chaque propriété."""
+    version = None
+    ranges = {name: [] for name in PROPERTIES}
+
+    for line in lines:
+        if version is None:
+            found = re.match(r"# DerivedCoreProperties-(\d+)\.(\d+)\.(\d+)", line)
+            if found:
+                version = tuple(int(part) for part in found.groups())
+
+        data = line.split("#", 1)[0].strip()
+        if not data:
+            continue
+        codepoints, name = (field.strip() for field in data.split(";"))
+        if name not in ranges:
+            continue
+        start, _, end = codepoints.partition("..")
+        ranges[name].append((int(start, 16), int(end or start, 16)))
+
+    if version is None:
+        sys.exit("en-tête `# DerivedCoreProperties-X.Y.Z.txt` introuvable")
+    return version, {name: merge(found) for name, found in ranges.items()}
+
+
+def merge(ranges):
+    """Trie les intervalles, et fusionne ceux qui se touchent."""
+    merged = []
+    for start, end in sorted(ranges):
+        if merged and start <= merged[-1][1] + 1:
+            merged[-1] = (merged[-1][0], max(merged[-1][1], end))
+        else:
+            merged.append((start, end))
+    return merged
+
+
+def table(constant, name, ranges):
+    """La constante Rust d'une table d'intervalles."""
+    items = [f"('\\u{{{start:X}}}', '\\u{{{end:X}}}')," for start, end in ranges]
+    lines = [
+        "\t" + " ".join(items[i : i + PER_LINE])
+        for i in range(0, len(items), PER_LINE)
+    ]
+    return (
+        "\n#[rustfmt::skip]\n"
+        f"/// Les intervalles, inclus, des points de code `{name}`.\n"
+        f"pub(crate) const {constant}: &[(char, char)] = &[\n"
+        + "\n".join(lines)
+        + "\n];\n"
+    )
+
+
+def main():
+    if len(sys.argv) != 2:
+        sys.exit(f"usage: {sys.argv[0]} DerivedCoreProperties.txt")
+
+    with open(sys.argv[1], encoding="utf-8") as source:
+        version, ranges = parse(source)
+
+    major, minor, update = version
+    output = HEADER.format(major=major, minor=minor, update=update)
+    output += table("XID_START", "XID_Start", ranges["XID_Start"])
+    output += table("XID_CONTINUE", "XID_Continue", ranges["XID_Continue"])
+    output += FOOTER
+    sys.stdout.write(output)
+
+
+if __name__ == "__main__":
+    main()
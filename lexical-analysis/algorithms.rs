@@ -8,8 +8,9 @@ use crate::comment::{CommentOutput, CommentParseError};
 use crate::identifier::{IdentifierOutput, IdentifierParseError};
 #[cfg(feature = "literal")]
 use crate::literal::{
-	BoolOutput, IntegerOutput, IntegerParseError, StringOutput,
-	StringParseError,
+	BoolOutput, ByteOutput, ByteParseError, ByteStringOutput, CharOutput,
+	CharParseError, Literal, LiteralParseError, StringOutput, StringParseError,
+	TemplateOutput,
 };
 
 // --------- //
@@ -27,16 +28,17 @@ pub trait TokenizerAlgorithms {
 	///    - `/* Mon commentaire */` => CommentType::Block
 	///
 	/// 1. CommentType::Line :
-	///     Si les deux points de code suivant sont U+002F SOLIDUS (/) suivi
-	/// d'un U+002F SOLIDUS (/), nous devons les consommer, ainsi que tous les
-	/// points de code suivant jusqu'à ce que nous rencontrons un point de code
-	/// U+000A LINE FEED (\n), autrement dit la fin de la ligne.
+	///    Si les deux points de code suivant sont U+002F SOLIDUS (/) suivi
+	///    d'un U+002F SOLIDUS (/), nous devons les consommer, ainsi que tous
+	///    les points de code suivant jusqu'à ce que nous rencontrons un point
+	///    de code U+000A LINE FEED (\n), autrement dit la fin de la ligne.
 	///
 	/// 2. CommentType::Block :
-	///   Si les deux points de code suivants sont U+002F SOLIDUS (/) suivi d'un
-	/// U+002A ASTERISK (*), nous devons les consommer ainsi que tous les points
-	/// de code suivants jusqu'au premier U+002A ASTERISK (*) suivi d'un
-	/// U+002F SOLIDUS (/), ou jusqu'à un point de code EOF (End Of File).
+	///    Si les deux points de code suivants sont U+002F SOLIDUS (/) suivi
+	///    d'un U+002A ASTERISK (*), nous devons les consommer ainsi que tous
+	///    les points de code suivants jusqu'au premier U+002A ASTERISK (*)
+	///    suivi d'un U+002F SOLIDUS (/), ou jusqu'à un point de code EOF (End
+	///    Of File).
 	fn consume_comments(&mut self) -> Result<CommentOutput, CommentParseError>;
 
 	#[cfg(feature = "identifier")]
@@ -48,14 +50,52 @@ pub trait TokenizerAlgorithms {
 	) -> Result<IdentifierOutput, IdentifierParseError>;
 
 	#[cfg(feature = "literal")]
-	/// Consommer un nombre, entier.
+	/// Consommer un nombre, entier ou à virgule flottante.
 	///
 	/// Un nombre peut être de type :
-	///   1. Integer::Decimal     : `71`, `10.0`, `.463`
+	///   1. Integer::Decimal     : `71`
 	///   2. Integer::Hexadecimal : `0x2A`
 	///   3. Integer::Binaire     : `0b10000000000`
 	///   4. Integer::Octal       : `0o2000`
-	fn consume_numeric(&mut self) -> Result<IntegerOutput, IntegerParseError>;
+	///   5. Literal::Float       : `10.0`, `.463`, `1.`, `1e-9`, `3f64`
+	///
+	/// Un nombre suivi d'un U+002E FULL STOP (.) est un nombre à virgule
+	/// flottante seulement si le point n'est suivi ni d'un autre point
+	/// (`1..2` est un intervalle, `Symbol::RANGE`), ni d'un début
+	/// d'identifiant (`1.max(2)`). Le point final (`1.`) dépend
+	/// du [profil](crate::LexerProfile).
+	///
+	/// Selon le [profil](crate::LexerProfile), les chiffres peuvent être
+	/// séparés par des `_` (`1_000_000`), et le nombre peut être suivi d'un
+	/// suffixe de type (`10u8`, `3i64`, `2.0f32`). Un nombre décimal peut
+	/// avoir un exposant (`1e-9`, `2.5E+3`). La mantisse, l'exposant et le
+	/// suffixe sont conservés séparément.
+	///
+	/// Sa valeur est analysée en un entier `u128`, ou en un `f64` pour un
+	/// nombre à virgule flottante. Un entier trop grand pour un `u128`, ou
+	/// pour le type de son suffixe, est une erreur
+	/// [IntegerParseError](crate::literal::IntegerParseError). Un nombre à
+	/// virgule flottante trop grand est une erreur
+	/// [FloatParseError](crate::literal::FloatParseError) ; un nombre
+	/// arrondi à zéro ou une perte de chiffres significatifs sont signalés
+	/// par un diagnostic non fatal.
+	fn consume_numeric(&mut self) -> Result<Literal, LiteralParseError>;
+
+	#[cfg(feature = "literal")]
+	/// Consommer un jeton numérique CSS.
+	///
+	/// Le nombre, éventuellement précédé d'un signe `+` ou `-`, est consommé
+	/// par [TokenizerAlgorithms::consume_numeric] ; son drapeau de type est
+	/// `integer` pour un nombre entier, `number` sinon. Il peut être suivi :
+	///   1. d'un U+0025 PERCENTAGE SIGN (%) : Literal::Percentage, `50%` ;
+	///   2. d'un identifiant, l'unité : Literal::Dimension, `10px`,
+	///      `-3e2deg` ;
+	///   3. sinon : Literal::Number, `-3`, `+.5`.
+	///
+	/// Comme le veut CSS Syntax §4.3.13, aucun nombre n'est rejeté : un
+	/// entier trop grand pour un `u128` garde le drapeau `integer`, et sa
+	/// valeur est calculée à partir de ses chiffres.
+	fn consume_numeric_token(&mut self) -> Result<Literal, LiteralParseError>;
 
 	#[cfg(feature = "literal")]
 	/// Consommer une chaîne de caractères.
@@ -71,9 +111,51 @@ pub trait TokenizerAlgorithms {
 	/// pour désigner un SEUL caractère (EX: `let ch: char = 'C';`).
 	fn consume_string(&mut self) -> Result<StringOutput, StringParseError>;
 
+	#[cfg(feature = "literal")]
+	/// Consommer un caractère.
+	///
+	/// Un caractère commence par un U+0027 APOSTROPHE ('), suivi d'un seul
+	/// caractère ou d'une séquence d'échappement, et se termine par un
+	/// U+0027 APOSTROPHE ('). Exemple, en MikuScript : `'C'`, `'\n'`.
+	fn consume_char(&mut self) -> Result<CharOutput, CharParseError>;
+
+	#[cfg(feature = "literal")]
+	/// Consommer un octet.
+	///
+	/// Un octet commence par un U+0062 LATIN SMALL LETTER B (b) suivi d'un
+	/// U+0027 APOSTROPHE ('), suivi d'un seul caractère ASCII ou d'une
+	/// séquence d'échappement, et se termine par un U+0027 APOSTROPHE (').
+	/// La séquence d'échappement `\xHH` accepte les valeurs jusqu'à `0xFF`.
+	fn consume_byte(&mut self) -> Result<ByteOutput, ByteParseError>;
+
+	#[cfg(feature = "literal")]
+	/// Consommer une chaîne d'octets.
+	///
+	/// Une chaîne d'octets commence par un U+0062 LATIN SMALL LETTER B (b)
+	/// suivi d'un U+0022 QUOTATION MARK ("), suivi d'une suite de
+	/// caractères ASCII ou de séquences d'échappement, et se termine par un
+	/// U+0022 QUOTATION MARK (").
+	fn consume_byte_string(
+		&mut self,
+	) -> Result<ByteStringOutput, ByteParseError>;
+
+	#[cfg(feature = "literal")]
+	/// Consommer un morceau d'une chaîne de caractères modèle.
+	///
+	/// Une chaîne de caractères modèle commence par un U+0060 GRAVE ACCENT
+	/// (`) et se termine par un U+0060 GRAVE ACCENT (`). Elle peut contenir
+	/// des interpolations `${expression}` : le morceau se termine alors par
+	/// `${`, les jetons de l'expression sont analysés normalement, puis le
+	/// `}` correspondant reprend la chaîne de caractères modèle.
+	///
+	/// Le morceau commence soit par U+0060 GRAVE ACCENT (`), soit par le
+	/// U+007D RIGHT CURLY BRACKET (}) fermant une interpolation.
+	fn consume_template(&mut self) -> Result<TemplateOutput, StringParseError>;
+
 	#[cfg(feature = "literal")]
 	/// Consommer un booléen.
 	///
-	/// Un booléen est un mot réservé qui peut être soit `true` soit `false`.
+	/// Un booléen est un mot réservé qui peut être soit `true` soit `false`,
+	/// lorsque le [profil](crate::LexerProfile::booleans) les reconnaît.
 	fn consume_boolean(&mut self) -> Option<BoolOutput>;
 }
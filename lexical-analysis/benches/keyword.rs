@@ -0,0 +1,88 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use lang_lexer::{
+	keyword::{Keyword, KeywordMatcher, KeywordTable},
+	profile::MIKUSCRIPT_KEYWORDS,
+};
+
+/// Mélange de mots-clés et d'identifiants, comme dans du code source.
+const WORDS: &[&str] = &[
+	"fn",
+	"main",
+	"let",
+	"mut",
+	"counter",
+	"for",
+	"item",
+	"in",
+	"items",
+	"if",
+	"is_empty",
+	"return",
+	"function",
+	"x",
+	"namespace",
+	"names",
+	"import",
+	"from",
+	"interface",
+	"value",
+	"continue",
+	"contains",
+	"await",
+	"async_task",
+	"while",
+	"w",
+];
+
+fn bench_keyword(c: &mut Criterion) {
+	let mut group = c.benchmark_group("keyword");
+
+	group.bench_function("match", |b| {
+		b.iter(|| {
+			for word in WORDS {
+				black_box(black_box(word).parse::<Keyword>().ok());
+			}
+		})
+	});
+
+	group.bench_function("matcher", |b| {
+		b.iter(|| {
+			for word in WORDS {
+				black_box(Keyword::from_chars(black_box(word).chars()));
+			}
+		})
+	});
+
+	let table = KeywordTable::from(MIKUSCRIPT_KEYWORDS);
+
+	group.bench_function("table", |b| {
+		b.iter(|| {
+			for word in WORDS {
+				black_box(table.get(black_box(word)));
+			}
+		})
+	});
+
+	group.bench_function("table-matcher", |b| {
+		b.iter(|| {
+			for word in WORDS {
+				let mut matcher = KeywordMatcher::new();
+				for ch in black_box(word).chars() {
+					matcher.push(ch);
+				}
+				black_box(table.get_matched(&matcher, word));
+			}
+		})
+	});
+
+	group.finish();
+}
+
+criterion_group!(benches, bench_keyword);
+criterion_main!(benches);
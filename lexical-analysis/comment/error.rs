@@ -4,6 +4,57 @@
 
 use core::fmt;
 
+use locale::{LocalizedMessage, Message};
+
+// -------- //
+// Constant //
+// -------- //
+
+const MESSAGES: &[Message] = &[
+	Message {
+		code: "comment-unterminated",
+		en: "The comment was not terminated because the EOF (End Of File) \
+		     code point was encountered. (location {line},{column})",
+		fr: "Le commentaire n'a pas été terminé dû fait d'une rencontre du \
+		     point de code EOF (End Of File). (emplacement {line},{column})",
+	},
+	Message {
+		code: "comment-is-not",
+		en: "This is not a comment.",
+		fr: "Il ne s'agit pas d'un commentaire.",
+	},
+	Message {
+		code: "abrupt-closing-of-empty-comment",
+		en: "The empty comment was abruptly closed by '>'.",
+		fr: "Le commentaire vide a été brusquement fermé par '>'.",
+	},
+	Message {
+		code: "eof-in-comment",
+		en: "The EOF (End Of File) code point was encountered in a comment, \
+		     the comment is closed.",
+		fr: "Le point de code EOF (End Of File) a été rencontré dans un \
+		     commentaire, le commentaire est fermé.",
+	},
+	Message {
+		code: "nested-comment",
+		en: "A nested comment '<!--' was encountered, comments cannot be \
+		     nested.",
+		fr: "Un commentaire imbriqué '<!--' a été rencontré, les \
+		     commentaires ne peuvent pas être imbriqués.",
+	},
+	Message {
+		code: "incorrectly-closed-comment",
+		en: "The comment was closed by '--!>' instead of '-->'.",
+		fr: "Le commentaire a été fermé par '--!>' au lieu de '-->'.",
+	},
+	Message {
+		code: "unexpected-null-character",
+		en: "Unexpected NULL code point in a comment, replaced by U+FFFD.",
+		fr: "Point de code NULL inattendu dans un commentaire, remplacé par \
+		     U+FFFD.",
+	},
+];
+
 // ----------- //
 // Énumération //
 // ----------- //
@@ -18,25 +69,61 @@ pub enum CommentParseError {
 
 	/// N'est pas un commentaire.
 	IsNot,
+
+	/// Erreur non fatale : un commentaire HTML vide est fermé par `>`,
+	/// par exemple `<!-->` ou `<!--->`.
+	AbruptClosingOfEmptyComment,
+
+	/// Erreur non fatale : le commentaire HTML n'a pas été terminé, le
+	/// commentaire est tout de même produit.
+	EofInComment,
+
+	/// Erreur non fatale : un commentaire HTML contient `<!--`.
+	NestedComment,
+
+	/// Erreur non fatale : un commentaire HTML est fermé par `--!>`.
+	IncorrectlyClosedComment,
+
+	/// Erreur non fatale : un commentaire HTML contient le point de code
+	/// U+0000 NULL, remplacé par U+FFFD REPLACEMENT CHARACTER.
+	UnexpectedNullCharacter,
 }
 
 // -------------- //
 // Implémentation // -> Interface
 // -------------- //
 
+impl LocalizedMessage for CommentParseError {
+	fn catalog(&self) -> &'static [Message] {
+		MESSAGES
+	}
+
+	fn code(&self) -> &'static str {
+		match self {
+			| Self::Unterminated { .. } => "comment-unterminated",
+			| Self::IsNot => "comment-is-not",
+			| Self::AbruptClosingOfEmptyComment => {
+				"abrupt-closing-of-empty-comment"
+			}
+			| Self::EofInComment => "eof-in-comment",
+			| Self::NestedComment => "nested-comment",
+			| Self::IncorrectlyClosedComment => "incorrectly-closed-comment",
+			| Self::UnexpectedNullCharacter => "unexpected-null-character",
+		}
+	}
+
+	fn placeholders(&self) -> Vec<(&'static str, String)> {
+		match self {
+			| Self::Unterminated { line, column } => {
+				vec![("line", line.to_string()), ("column", column.to_string())]
+			}
+			| _ => vec![],
+		}
+	}
+}
+
 impl fmt::Display for CommentParseError {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		write!(
-			f,
-			"{}",
-			match self {
-				| Self::Unterminated { line, column } => format!(
-					"Le commentaire n'a pas été terminé dû fait d'une \
-					rencontre du point de code EOF (End Of File). \
-					(emplacement {line},{column})"
-				),
-				| Self::IsNot => "Il ne s'agit pas d'un commentaire.".into(),
-			}
-		)
+		write!(f, "{}", self.message())
 	}
 }
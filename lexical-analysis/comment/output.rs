@@ -4,7 +4,7 @@
 
 use core::{fmt, str};
 
-use location::Location;
+use location::Span;
 
 // --------- //
 // Structure //
@@ -20,8 +20,8 @@ pub struct CommentOutput {
 	/// Le type de commentaire.
 	pub ty: CommentType,
 
-	/// L'emplacement du commentaire dans le code source.
-	pub location: Location,
+	/// La portion du code source occupée par le commentaire.
+	pub span: Span,
 }
 
 // ----------- //
@@ -54,16 +54,92 @@ pub enum CommentType {
 	///     - HTML:       <!-- -->
 	#[default]
 	Block,
+
+	/// Commentaire de documentation externe, d'une seule ligne. Documente
+	/// l'élément qui le suit.
+	///
+	/// Exemple: `/// Ma documentation`.
+	OuterDocLine,
+
+	/// Commentaire de documentation interne, d'une seule ligne. Documente
+	/// l'élément qui le contient.
+	///
+	/// Exemple: `//! Ma documentation`.
+	InnerDocLine,
+
+	/// Commentaire de documentation externe, de plusieurs lignes.
+	///
+	/// Exemple: `/** Ma documentation */`.
+	OuterDocBlock,
+
+	/// Commentaire de documentation interne, de plusieurs lignes.
+	///
+	/// Exemple: `/*! Ma documentation */`.
+	InnerDocBlock,
 }
 
 // -------------- //
 // Implémentation //
 // -------------- //
 
+impl CommentOutput {
+	/// Nettoie le texte d'un commentaire de documentation : retire l'espace
+	/// suivant le marqueur de début et, pour un commentaire de plusieurs
+	/// lignes, la décoration `*` au début de chaque ligne ainsi que les
+	/// lignes vides de début et de fin.
+	pub(crate) fn clean_doc(&mut self) {
+		let data = match self.ty {
+			| CommentType::OuterDocLine | CommentType::InnerDocLine => {
+				strip_one_space(&self.data).to_owned()
+			}
+			| CommentType::OuterDocBlock | CommentType::InnerDocBlock => {
+				let mut lines: Vec<_> = self
+					.data
+					.lines()
+					.enumerate()
+					.map(|(index, line)| {
+						let trimmed = line.trim_start();
+						match trimmed.strip_prefix('*') {
+							| Some(line) if index > 0 => strip_one_space(line),
+							| _ if index > 0 => trimmed,
+							| _ => strip_one_space(line),
+						}
+						.trim_end()
+					})
+					.collect();
+
+				while lines.last().is_some_and(|line| line.is_empty()) {
+					lines.pop();
+				}
+				let blank = lines.iter().take_while(|l| l.is_empty()).count();
+				lines[blank..].join("\n")
+			}
+			| CommentType::Line | CommentType::Block => return,
+		};
+
+		self.data = data;
+	}
+}
+
 impl CommentType {
 	pub fn define(&mut self, ty: Self) {
 		*self = ty;
 	}
+
+	/// Commentaire de documentation.
+	pub fn is_doc(self) -> bool {
+		!matches!(self, Self::Line | Self::Block)
+	}
+
+	/// Commentaire de documentation interne.
+	pub fn is_inner_doc(self) -> bool {
+		matches!(self, Self::InnerDocLine | Self::InnerDocBlock)
+	}
+}
+
+/// Retire un seul espace au début d'une ligne.
+fn strip_one_space(line: &str) -> &str {
+	line.strip_prefix(' ').unwrap_or(line)
 }
 
 // -------------- //
@@ -77,9 +153,13 @@ impl str::FromStr for CommentType {
 		Ok(match input.to_uppercase().as_ref() {
 			| "LINE" => Self::Line,
 			| "BLOCK" => Self::Block,
+			| "OUTER-DOC-LINE" => Self::OuterDocLine,
+			| "INNER-DOC-LINE" => Self::InnerDocLine,
+			| "OUTER-DOC-BLOCK" => Self::OuterDocBlock,
+			| "INNER-DOC-BLOCK" => Self::InnerDocBlock,
 			| _ => {
 				return Err(
-					r#"Un commentaire de type "LINE" ou "BLOCK" est attendu"#,
+					r#"Un commentaire de type "LINE", "BLOCK", "OUTER-DOC-LINE", "INNER-DOC-LINE", "OUTER-DOC-BLOCK" ou "INNER-DOC-BLOCK" est attendu"#,
 				);
 			}
 		})
@@ -94,6 +174,10 @@ impl fmt::Display for CommentType {
 			match self {
 				| Self::Line => "LINE",
 				| Self::Block => "BLOCK",
+				| Self::OuterDocLine => "OUTER-DOC-LINE",
+				| Self::InnerDocLine => "INNER-DOC-LINE",
+				| Self::OuterDocBlock => "OUTER-DOC-BLOCK",
+				| Self::InnerDocBlock => "INNER-DOC-BLOCK",
 			}
 		)
 	}
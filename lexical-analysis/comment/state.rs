@@ -40,10 +40,48 @@ pub enum CommentState {
 	SinglelineEnd,
 
 	/// L'état d'un commentaire de plusieurs lignes.
-	Multiline,
-	MultilineAfterFirstCodePoint, // *
+	///
+	/// La profondeur correspond au nombre de commentaires imbriqués ouverts
+	/// à l'intérieur du commentaire, `0` pour le commentaire le plus
+	/// extérieur.
+	///
+	/// L'état suivant de cet état est :
+	///   - [CommentState::Multiline], avec une profondeur augmentée de 1,
+	///     lorsqu'un commentaire imbriqué est ouvert ;
+	///   - [CommentState::MultilineAfterFirstCodePoint].
+	Multiline {
+		depth: usize,
+	},
+
+	/// Le premier point de code de la fin du commentaire a été rencontré
+	/// (par exemple `*` pour `*/`).
+	///
+	/// L'état suivant de cet état est :
+	///   - [CommentState::Multiline], avec une profondeur diminuée de 1,
+	///     lorsqu'un commentaire imbriqué est fermé ;
+	///   - [CommentState::MultilineEnd].
+	MultilineAfterFirstCodePoint {
+		depth: usize,
+	},
+
 	/// L'état de fin de commentaire de plusieurs lignes.
 	MultilineEnd,
+
+	/// Les états d'un commentaire HTML `<!-- -->`, voir
+	/// <https://html.spec.whatwg.org/multipage/parsing.html#comment-start-state>.
+	///
+	/// Le début `<!--` a déjà été consommé. Les états suivants de ces états
+	/// sont les autres états HTML et [CommentState::MultilineEnd].
+	HtmlCommentStart,
+	HtmlCommentStartDash,
+	HtmlComment,
+	HtmlCommentLessThanSign,
+	HtmlCommentLessThanSignBang,
+	HtmlCommentLessThanSignBangDash,
+	HtmlCommentLessThanSignBangDashDash,
+	HtmlCommentEndDash,
+	HtmlCommentEnd,
+	HtmlCommentEndBang,
 }
 
 // -------------- //
@@ -4,6 +4,18 @@
 
 use core::fmt;
 
+use locale::{LocalizedMessage, Message};
+
+// -------- //
+// Constant //
+// -------- //
+
+const MESSAGES: &[Message] = &[Message {
+	code: "delimiter-invalid",
+	en: "'{found}' is not a valid delimiter.",
+	fr: "'{found}' n'est pas un délimiteur valide.",
+}];
+
 // ----------- //
 // Énumération //
 // ----------- //
@@ -20,15 +32,26 @@ pub enum DelimiterParseError {
 // Implémentation // -> Interface
 // -------------- //
 
+impl LocalizedMessage for DelimiterParseError {
+	fn catalog(&self) -> &'static [Message] {
+		MESSAGES
+	}
+
+	fn code(&self) -> &'static str {
+		match self {
+			| Self::Invalid { .. } => "delimiter-invalid",
+		}
+	}
+
+	fn placeholders(&self) -> Vec<(&'static str, String)> {
+		match self {
+			| Self::Invalid { found } => vec![("found", found.to_owned())],
+		}
+	}
+}
+
 impl fmt::Display for DelimiterParseError {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		write!(
-			f,
-			"{}",
-			match self {
-				| Self::Invalid { .. } =>
-					"Il ne s'agit pas d'un délimiteur valide.",
-			}
-		)
+		write!(f, "{}", self.message())
 	}
 }
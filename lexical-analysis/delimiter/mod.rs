@@ -88,7 +88,7 @@ where
 			| CodePoint::Unit(u) if u.is('_') => {
 				Self::Symbol(Symbol::UNDERSCORE)
 			}
-			| CodePoint::Unit(u) if u.is('`') => Self::Symbol(Symbol::TILDE),
+			| CodePoint::Unit(u) if u.is('`') => Self::Symbol(Symbol::BACKTICK),
 			| CodePoint::QUESTION_MARK => Self::Symbol(Symbol::QUESTION_MARK),
 
 			// Les délimiteurs paires.
@@ -2,7 +2,7 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
 
-use location::Location;
+use location::Span;
 
 use super::Delimiter;
 
@@ -10,7 +10,13 @@ use super::Delimiter;
 // Structure //
 // --------- //
 
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
 pub struct DelimiterOutput {
+	/// Le délimiteur analysé.
 	pub delimiter: Delimiter,
-	pub location: Location,
+
+	/// La portion du code source occupée par le délimiteur.
+	pub span: Span,
 }
@@ -19,7 +19,7 @@ delim! { - Symbol -
 	COLON           = ':';
 	SEMICOLON       = ';';
 	UNDERSCORE      = '_';
-	TILDE           = '`';
+	BACKTICK        = '`';
 	QUESTION_MARK   = '?';
 
 	SKINNY_ARROW    = "->";
@@ -2,6 +2,9 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
 
+use core::fmt;
+
+use locale::{LocalizedMessage, Message};
 use stream::prelude::StreamIteratorError;
 
 #[cfg(feature = "comment")]
@@ -9,9 +12,22 @@ use crate::comment::CommentParseError;
 use crate::{
 	delimiter::error::DelimiterParseError,
 	identifier::IdentifierParseError,
-	literal::{IntegerParseError, LiteralParseError, StringParseError},
+	literal::{
+		ByteParseError, CharParseError, FloatParseError, IntegerParseError,
+		LiteralParseError, StringParseError,
+	},
 };
 
+// -------- //
+// Constant //
+// -------- //
+
+const MESSAGES: &[Message] = &[Message {
+	code: "lexical-eos",
+	en: "Unexpected end of stream.",
+	fr: "Fin du flux inattendue.",
+}];
+
 // ----------- //
 // Énumération //
 // ----------- //
@@ -72,6 +88,34 @@ impl From<IntegerParseError> for LexicalError {
 	}
 }
 
+#[cfg(feature = "literal")]
+impl From<FloatParseError> for LexicalError {
+	fn from(error: FloatParseError) -> Self {
+		Self::Literal(LiteralParseError::Float(error))
+	}
+}
+
+#[cfg(feature = "literal")]
+impl From<LiteralParseError> for LexicalError {
+	fn from(error: LiteralParseError) -> Self {
+		Self::Literal(error)
+	}
+}
+
+#[cfg(feature = "literal")]
+impl From<ByteParseError> for LexicalError {
+	fn from(error: ByteParseError) -> Self {
+		Self::Literal(LiteralParseError::Byte(error))
+	}
+}
+
+#[cfg(feature = "literal")]
+impl From<CharParseError> for LexicalError {
+	fn from(error: CharParseError) -> Self {
+		Self::Literal(LiteralParseError::Char(error))
+	}
+}
+
 #[cfg(feature = "literal")]
 impl From<StringParseError> for LexicalError {
 	fn from(error: StringParseError) -> Self {
@@ -79,8 +123,95 @@ impl From<StringParseError> for LexicalError {
 	}
 }
 
+impl LocalizedMessage for LexicalError {
+	fn catalog(&self) -> &'static [Message] {
+		match self {
+			#[cfg(feature = "comment")]
+			| Self::Comment(err) => err.catalog(),
+			#[cfg(feature = "delimiter")]
+			| Self::Delimiter(err) => err.catalog(),
+			#[cfg(feature = "identifier")]
+			| Self::Identifier(err) => err.catalog(),
+			#[cfg(feature = "literal")]
+			| Self::Literal(err) => err.catalog(),
+			| Self::EOS => MESSAGES,
+		}
+	}
+
+	fn code(&self) -> &'static str {
+		match self {
+			#[cfg(feature = "comment")]
+			| Self::Comment(err) => err.code(),
+			#[cfg(feature = "delimiter")]
+			| Self::Delimiter(err) => err.code(),
+			#[cfg(feature = "identifier")]
+			| Self::Identifier(err) => err.code(),
+			#[cfg(feature = "literal")]
+			| Self::Literal(err) => err.code(),
+			| Self::EOS => "lexical-eos",
+		}
+	}
+
+	fn placeholders(&self) -> Vec<(&'static str, String)> {
+		match self {
+			#[cfg(feature = "comment")]
+			| Self::Comment(err) => err.placeholders(),
+			#[cfg(feature = "delimiter")]
+			| Self::Delimiter(err) => err.placeholders(),
+			#[cfg(feature = "identifier")]
+			| Self::Identifier(err) => err.placeholders(),
+			#[cfg(feature = "literal")]
+			| Self::Literal(err) => err.placeholders(),
+			| Self::EOS => vec![],
+		}
+	}
+}
+
+impl fmt::Display for LexicalError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.message())
+	}
+}
+
 impl StreamIteratorError for LexicalError {
 	fn eos() -> Self {
 		Self::EOS
 	}
 }
+
+// ---- //
+// Test //
+// ---- //
+
+#[cfg(test)]
+mod tests {
+	use locale::Locale;
+
+	use super::*;
+	use crate::{comment::CommentParseError, literal::StringParseError};
+
+	#[test]
+	fn test_localized_messages() {
+		let err = LexicalError::from(StringParseError::Unterminated {
+			line: 3,
+			column: 14,
+		});
+		assert_eq!(err.code(), "string-unterminated");
+		assert_eq!(
+			err.localize(Locale::English),
+			"The string is not terminated at position 'L:3,C:14'."
+		);
+		assert_eq!(
+			err.localize(Locale::French),
+			"La chaîne de caractères n'est pas terminée en position \
+			 'L:3,C:14'."
+		);
+
+		let err = LexicalError::from(CommentParseError::IsNot);
+		assert_eq!(err.localize(Locale::English), "This is not a comment.");
+		assert_eq!(
+			err.localize(Locale::French),
+			"Il ne s'agit pas d'un commentaire."
+		);
+	}
+}
@@ -0,0 +1,124 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+use std::collections::HashMap;
+
+use location::Span;
+use unicode_security::MixedScript;
+
+use super::IdentifierParseError;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Détecte les identifiants trompeurs, selon le standard UTS #39 d'Unicode.
+///
+/// Le détecteur retient le premier identifiant rencontré pour chaque
+/// [squelette](ConfusableDetector::skeleton), ainsi que la première
+/// occurrence de chaque identifiant : un identifiant n'est signalé qu'une
+/// seule fois, à sa première occurrence. Il peut être transmis d'un
+/// [analyseur](crate::Tokenizer) à l'autre, afin de comparer les
+/// identifiants de tous les fichiers d'un projet.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(Default)]
+#[derive(PartialEq, Eq)]
+pub struct ConfusableDetector {
+	/// Le premier identifiant, et son emplacement, de chaque squelette.
+	skeletons: HashMap<String, (String, Span)>,
+
+	/// L'emplacement de la première occurrence de chaque identifiant.
+	identifiers: HashMap<String, Span>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl ConfusableDetector {
+	/// Détecteur n'ayant rencontré aucun identifiant.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Le squelette de l'identifiant : deux identifiants visuellement
+	/// confondables ont le même squelette. `раура`, écrit en cyrillique,
+	/// et `paypa` ont pour squelette `paypa`.
+	pub fn skeleton(identifier: &str) -> String {
+		unicode_security::skeleton(identifier).collect()
+	}
+
+	/// Vérifie l'identifiant `identifier`, de squelette `skeleton`, situé à
+	/// l'emplacement `span`, et retourne les erreurs non fatales de sa
+	/// première occurrence ; les suivantes ne sont pas signalées :
+	///   1. [IdentifierParseError::MixedScript] lorsque l'identifiant
+	///      mélange plusieurs écritures ;
+	///   2. [IdentifierParseError::Confusable] lorsqu'un autre identifiant
+	///      déjà rencontré a le même squelette. Comme deux identifiants
+	///      ASCII peuvent avoir le même squelette (`rn` et `m`), seules les
+	///      paires contenant un point de code non ASCII sont signalées.
+	pub fn check(
+		&mut self,
+		identifier: &str,
+		skeleton: &str,
+		span: Span,
+	) -> Vec<IdentifierParseError> {
+		let mut errors = Vec::new();
+
+		if self.identifiers.contains_key(identifier) {
+			return errors;
+		}
+		self.identifiers.insert(identifier.to_owned(), span);
+
+		if !identifier.is_single_script() {
+			errors.push(IdentifierParseError::MixedScript {
+				found: identifier.to_owned(),
+				span,
+			});
+		}
+
+		match self.skeletons.get(skeleton) {
+			| Some((other, other_span)) => {
+				if other != identifier
+					&& !(other.is_ascii() && identifier.is_ascii())
+				{
+					errors.push(IdentifierParseError::Confusable {
+						found: identifier.to_owned(),
+						other: other.to_owned(),
+						other_span: *other_span,
+					});
+				}
+			}
+			| None => {
+				self.skeletons
+					.insert(skeleton.to_owned(), (identifier.to_owned(), span));
+			}
+		}
+
+		errors
+	}
+
+	/// Oublie l'occurrence de l'identifiant `identifier`, de squelette
+	/// `skeleton`, située à l'emplacement `span` : l'analyseur revient en
+	/// arrière et vérifiera à nouveau cette occurrence.
+	pub(crate) fn forget(
+		&mut self,
+		identifier: &str,
+		skeleton: &str,
+		span: Span,
+	) {
+		if self.identifiers.get(identifier) == Some(&span) {
+			self.identifiers.remove(identifier);
+		}
+		if self
+			.skeletons
+			.get(skeleton)
+			.is_some_and(|(other, other_span)| {
+				other == identifier && *other_span == span
+			}) {
+			self.skeletons.remove(skeleton);
+		}
+	}
+}
@@ -2,7 +2,43 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
 
-use crate::keyword::Keyword;
+use core::fmt;
+
+use locale::{LocalizedMessage, Message};
+use location::Span;
+
+use crate::keyword::KeywordId;
+
+// -------- //
+// Constant //
+// -------- //
+
+const MESSAGES: &[Message] = &[
+	Message {
+		code: "identifier-is-not",
+		en: "The character '{found}' cannot start an identifier.",
+		fr: "Le caractère '{found}' ne peut pas commencer un identifiant.",
+	},
+	Message {
+		code: "identifier-is-keyword",
+		en: "The identifier {found} is a reserved keyword.",
+		fr: "L'identifiant {found} est un mot-clé réservé.",
+	},
+	Message {
+		code: "identifier-mixed-script",
+		en: "The identifier '{found}' at position '{span}' mixes several \
+		     scripts.",
+		fr: "L'identifiant '{found}' en position '{span}' mélange plusieurs \
+		     écritures.",
+	},
+	Message {
+		code: "identifier-confusable",
+		en: "The identifier '{found}' can be confused with '{other}' at \
+		     position '{other_span}'.",
+		fr: "L'identifiant '{found}' peut être confondu avec '{other}' en \
+		     position '{other_span}'.",
+	},
+];
 
 // ----------- //
 // Énumération //
@@ -12,6 +48,70 @@ use crate::keyword::Keyword;
 #[derive(Clone)]
 #[derive(PartialEq, Eq)]
 pub enum IdentifierParseError {
-	/// L'identifiant analysé correspond à un mot-clé.
-	IsKeyword { found: Keyword },
+	/// Le point de code ne peut pas commencer un identifiant.
+	IsNot { found: char },
+
+	/// L'identifiant analysé correspond à un mot-clé, écrit `spelling`.
+	IsKeyword { found: KeywordId, spelling: String },
+
+	/// L'identifiant mélange plusieurs écritures : `pаypal`, dont le `а`
+	/// est cyrillique. Il s'agit d'une erreur non fatale.
+	MixedScript { found: String, span: Span },
+
+	/// L'identifiant a le même squelette qu'un autre identifiant, `other` :
+	/// ils sont visuellement confondables. Il s'agit d'une erreur non
+	/// fatale, émise à l'emplacement de l'identifiant `found`.
+	Confusable {
+		found: String,
+		other: String,
+		other_span: Span,
+	},
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl LocalizedMessage for IdentifierParseError {
+	fn catalog(&self) -> &'static [Message] {
+		MESSAGES
+	}
+
+	fn code(&self) -> &'static str {
+		match self {
+			| Self::IsNot { .. } => "identifier-is-not",
+			| Self::IsKeyword { .. } => "identifier-is-keyword",
+			| Self::MixedScript { .. } => "identifier-mixed-script",
+			| Self::Confusable { .. } => "identifier-confusable",
+		}
+	}
+
+	fn placeholders(&self) -> Vec<(&'static str, String)> {
+		match self {
+			| Self::IsNot { found } => vec![("found", found.to_string())],
+			| Self::IsKeyword { spelling, .. } => {
+				vec![("found", spelling.to_owned())]
+			}
+			| Self::MixedScript { found, span } => {
+				vec![("found", found.to_owned()), ("span", span.to_string())]
+			}
+			| Self::Confusable {
+				found,
+				other,
+				other_span,
+			} => {
+				vec![
+					("found", found.to_owned()),
+					("other", other.to_owned()),
+					("other_span", other_span.to_string()),
+				]
+			}
+		}
+	}
+}
+
+impl fmt::Display for IdentifierParseError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.message())
+	}
 }
@@ -2,7 +2,11 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
 
+mod confusable;
 mod error;
 mod output;
 
-pub use self::{error::IdentifierParseError, output::IdentifierOutput};
+pub use self::{
+	confusable::ConfusableDetector, error::IdentifierParseError,
+	output::IdentifierOutput,
+};
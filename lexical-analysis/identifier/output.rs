@@ -2,7 +2,10 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
 
-use location::Location;
+use location::Span;
+
+#[cfg(feature = "keyword")]
+use crate::keyword::KeywordId;
 
 // --------- //
 // Structure //
@@ -12,9 +15,46 @@ use location::Location;
 #[derive(Clone)]
 #[derive(PartialEq, Eq)]
 pub struct IdentifierOutput {
-	/// L'identifiant analysé.
+	/// L'identifiant analysé, normalisé en forme NFC : `café` écrit avec
+	/// un U+0301 COMBINING ACUTE ACCENT est conservé avec un `é` composé.
+	/// Les séquences d'échappement d'un identifiant CSS y sont remplacées.
 	pub identifier: String,
 
-	/// L'emplacement de l'identifiant dans la source.
-	pub location: Location,
+	/// La forme normalisée de l'identifiant, en minuscules ASCII, lorsque
+	/// le [profil](crate::LexerProfile) est insensible à la casse ASCII.
+	pub normalized: Option<String>,
+
+	/// Le squelette UTS #39 de l'identifiant, lorsque la
+	/// [détection des identifiants confondables](super::ConfusableDetector)
+	/// est activée.
+	pub skeleton: Option<String>,
+
+	#[cfg(feature = "keyword")]
+	/// Le mot-clé contextuel que l'identifiant peut désigner, selon le
+	/// contexte du parseur : `from` dans `import x from "y"`.
+	pub keyword: Option<KeywordId>,
+
+	/// La portion du code source occupée par l'identifiant.
+	pub span: Span,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl IdentifierOutput {
+	/// La forme de l'identifiant à comparer : normalisée lorsque le profil
+	/// est insensible à la casse ASCII, telle qu'elle est écrite sinon.
+	pub fn normalized(&self) -> &str {
+		self.normalized.as_deref().unwrap_or(&self.identifier)
+	}
+
+	/// L'identifiant correspond à `name`, sans tenir compte de la casse ASCII
+	/// lorsque le profil y est insensible.
+	pub fn is(&self, name: &str) -> bool {
+		match &self.normalized {
+			| Some(normalized) => normalized.eq_ignore_ascii_case(name),
+			| None => self.identifier == name,
+		}
+	}
 }
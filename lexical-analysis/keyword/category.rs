@@ -0,0 +1,51 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+use core::fmt;
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+/// La catégorie d'un [mot-clé](super::Keyword), pour les éditeurs et les
+/// outils de formatage.
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+#[derive(Hash)]
+pub enum KeywordCategory {
+	/// Déclaration : `fn`, `let`, `struct`.
+	Declaration,
+
+	/// Structure de contrôle : `if`, `for`, `return`.
+	ControlFlow,
+
+	/// Module : `mod`, `import`, `export`.
+	Module,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl KeywordCategory {
+	/// Le nom de la catégorie.
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			| Self::Declaration => "declaration",
+			| Self::ControlFlow => "control-flow",
+			| Self::Module => "module",
+		}
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl fmt::Display for KeywordCategory {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.as_str())
+	}
+}
@@ -0,0 +1,26 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+use crate::Kword;
+
+Kword! {
+	- Charset   as charset
+	- Import    as import    : Module
+	- Namespace as namespace : Module
+	- Layer     as layer     : Module
+
+	- Media     as media     : ControlFlow
+	- Supports  as supports  : ControlFlow
+	- Container as container : ControlFlow
+	- Scope     as scope     : ControlFlow
+
+	- Page      as page      : Declaration
+	- Keyframes as keyframes : Declaration
+	- Property  as property  : Declaration
+
+	- Inherit as inherit
+	- Initial as initial
+	- Unset   as unset
+	- Revert  as revert
+}
@@ -4,6 +4,18 @@
 
 use core::fmt;
 
+use locale::{LocalizedMessage, Message};
+
+// -------- //
+// Constant //
+// -------- //
+
+const MESSAGES: &[Message] = &[Message {
+	code: "keyword-unknown",
+	en: "The keyword {found} does not exist.",
+	fr: "Le mot-clé {found} n'existe pas.",
+}];
+
 // ----------- //
 // Énumération //
 // ----------- //
@@ -19,15 +31,26 @@ pub enum KeywordParseError {
 // Implémentation // -> Interface
 // -------------- //
 
+impl LocalizedMessage for KeywordParseError {
+	fn catalog(&self) -> &'static [Message] {
+		MESSAGES
+	}
+
+	fn code(&self) -> &'static str {
+		match self {
+			| Self::Unknown { .. } => "keyword-unknown",
+		}
+	}
+
+	fn placeholders(&self) -> Vec<(&'static str, String)> {
+		match self {
+			| Self::Unknown { found } => vec![("found", found.to_owned())],
+		}
+	}
+}
+
 impl fmt::Display for KeywordParseError {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		write!(
-			f,
-			"{}",
-			match self {
-				| Self::Unknown { found } =>
-					format!("Le mot-clé {} n'existe pas.", found),
-			}
-		)
+		write!(f, "{}", self.message())
 	}
 }
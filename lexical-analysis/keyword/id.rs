@@ -0,0 +1,85 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+use core::fmt;
+
+use super::KeywordCategory;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// L'identité d'un mot-clé, indépendante du langage qui le définit.
+///
+/// Chaque ensemble de mots-clés déclaré par le macro `Kword!` (MikuScript,
+/// [MSCSS](super::mscss), [CSS](super::css)) convertit ses mots-clés en
+/// identités, ce qui permet à une [table](super::KeywordTable) de mélanger
+/// les mots-clés de plusieurs langages. Un langage peut aussi déclarer ses
+/// mots-clés directement, avec [KeywordId::new].
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+#[derive(Hash)]
+pub struct KeywordId {
+	/// Le nom du mot-clé, en minuscules : `function`.
+	name: &'static str,
+
+	/// Les orthographes du mot-clé : `fn`, `func` et `function`.
+	aliases: &'static [&'static str],
+
+	/// La catégorie du mot-clé, lorsqu'elle est définie.
+	category: Option<KeywordCategory>,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl KeywordId {
+	/// Mot-clé nommé `name`, écrit avec l'une des orthographes `aliases`,
+	/// dont la première est l'orthographe canonique.
+	pub const fn new(
+		name: &'static str,
+		aliases: &'static [&'static str],
+		category: Option<KeywordCategory>,
+	) -> Self {
+		assert!(!aliases.is_empty(), "mot-clé sans orthographe");
+		Self {
+			name,
+			aliases,
+			category,
+		}
+	}
+
+	/// Le nom du mot-clé, en minuscules : `function`.
+	pub const fn as_str(self) -> &'static str {
+		self.name
+	}
+
+	/// Les orthographes du mot-clé.
+	pub const fn aliases(self) -> &'static [&'static str] {
+		self.aliases
+	}
+
+	/// L'orthographe canonique du mot-clé, la première de ses
+	/// [orthographes](KeywordId::aliases) : `fn` pour `function`.
+	pub const fn canonical(self) -> &'static str {
+		self.aliases[0]
+	}
+
+	/// La catégorie du mot-clé, lorsqu'elle est définie.
+	pub const fn category(self) -> Option<KeywordCategory> {
+		self.category
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl fmt::Display for KeywordId {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.name)
+	}
+}
@@ -4,9 +4,14 @@
 
 #[macro_export]
 macro_rules! Kword {
+	(@category) => { None };
+	(@category $category:ident) => {
+		Some($crate::keyword::KeywordCategory::$category)
+	};
+
 	(
 	$(
-		- $name:ident as $( $variant:ident )|*
+		- $name:ident as $( $variant:ident )|* $( : $category:ident )?
 	)*
 	) => {
 // ----------- //
@@ -16,6 +21,7 @@ macro_rules! Kword {
 #[derive(Debug)]
 #[derive(Copy, Clone)]
 #[derive(PartialEq, Eq)]
+#[derive(Hash)]
 pub enum Keyword {
 	$(
 		#[doc = "Le mot-clé : "]
@@ -24,12 +30,106 @@ pub enum Keyword {
 	),*
 }
 
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl Keyword {
+	/// Tous les mots-clés, dans l'ordre de leur déclaration.
+	pub const ALL: &'static [Keyword] = &[ $( Self::$name ),* ];
+
+	/// Toutes les orthographes des mots-clés, à partir desquelles est
+	/// construite leur table de hachage parfaite.
+	pub const SPELLINGS: &'static [(&'static str, Keyword)] = &[
+	$(
+		$( (stringify!($variant), Self::$name), )*
+	)*
+	];
+
+	/// La longueur de la plus longue orthographe.
+	pub const MAX_SPELLING_LEN: usize =
+		$crate::keyword::r#macro::max_len(Self::SPELLINGS);
+
+	/// La table de hachage parfaite des orthographes, calculée à la
+	/// compilation.
+	#[doc(hidden)]
+	pub const PERFECT_HASH: $crate::keyword::r#macro::PerfectHash<
+		{ $crate::keyword::r#macro::table_size(Keyword::SPELLINGS.len()) },
+	> = $crate::keyword::r#macro::PerfectHash::new(Keyword::SPELLINGS);
+
+	/// L'index, dans `SPELLINGS`, d'une orthographe.
+	#[inline]
+	pub fn spelling_index(spelling: &[u8]) -> Option<usize> {
+		Self::PERFECT_HASH.lookup(Self::SPELLINGS, spelling)
+	}
+
+	/// Les orthographes du mot-clé : `fn`, `func` et `function` pour
+	/// `Keyword::Function`.
+	pub const fn aliases(self) -> &'static [&'static str] {
+		match self {
+		$(
+			| Self::$name => &[ $( stringify!($variant) ),* ],
+		)*
+		}
+	}
+
+	/// L'orthographe canonique du mot-clé, la première de ses
+	/// [orthographes](Keyword::aliases) : `fn` pour `Keyword::Function`.
+	pub const fn canonical(self) -> &'static str {
+		self.aliases()[0]
+	}
+
+	/// Le nom du mot-clé, en minuscules : `function` pour
+	/// `Keyword::Function`. C'est le nom affiché par
+	/// [Display](core::fmt::Display).
+	pub const fn as_str(self) -> &'static str {
+		match self {
+		$(
+			| Self::$name => {
+				const NAME: [u8; stringify!($name).len()] =
+					$crate::keyword::r#macro::to_ascii_lowercase(
+						stringify!($name),
+					);
+				match core::str::from_utf8(&NAME) {
+					| Ok(name) => name,
+					| Err(_) => unreachable!(),
+				}
+			}
+		)*
+		}
+	}
+
+	/// La catégorie du mot-clé, lorsqu'elle est définie.
+	pub const fn category(self) -> Option<$crate::keyword::KeywordCategory> {
+		match self {
+		$(
+			| Self::$name => $crate::Kword!(@category $( $category )?),
+		)*
+		}
+	}
+
+	/// L'identité du mot-clé, enregistrée dans une table de mots-clés.
+	pub const fn id(self) -> $crate::keyword::KeywordId {
+		$crate::keyword::KeywordId::new(
+			self.as_str(),
+			self.aliases(),
+			self.category(),
+		)
+	}
+
+	/// Le mot-clé dont l'identité est `id`, lorsqu'il appartient à cet
+	/// ensemble de mots-clés.
+	pub fn from_id(id: $crate::keyword::KeywordId) -> Option<Self> {
+		Self::ALL.iter().copied().find(|keyword| keyword.id() == id)
+	}
+}
+
 // -------------- //
 // Implémentation // ->  Interface
 // -------------- //
 
 impl core::str::FromStr for Keyword {
-	type Err = self::error::KeywordParseError;
+	type Err = $crate::keyword::KeywordParseError;
 
 	fn from_str(input: &str) -> Result<Self, Self::Err> {
 		Ok(match input {
@@ -45,18 +145,191 @@ impl core::str::FromStr for Keyword {
 	}
 }
 
+impl From<Keyword> for $crate::keyword::KeywordId {
+	fn from(keyword: Keyword) -> Self {
+		keyword.id()
+	}
+}
+
 impl core::fmt::Display for Keyword {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-		write!(
-			f,
-			"{}",
-			match self {
-			$(
-				| Self::$name => stringify!($name).to_ascii_lowercase()
-			),*
-			}
-		)
+		write!(f, "{}", self.as_str())
 	}
 }
+
+// NOTE: la table de hachage parfaite est calculée même lorsqu'elle n'est pas
+// utilisée : une orthographe dupliquée est une erreur de compilation.
+const _: () = {
+	_ = Keyword::PERFECT_HASH;
+};
 	};
 }
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Table de hachage parfaite d'orthographes, calculée à la compilation par
+/// le macro `Kword!` : la graine de la fonction de hachage, et l'index de
+/// l'orthographe de chaque emplacement.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct PerfectHash<const N: usize> {
+	seed: u32,
+	table: [u8; N],
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl<const N: usize> PerfectHash<N> {
+	/// Emplacement vide de la table.
+	const EMPTY: u8 = u8::MAX;
+
+	/// Cherche, à la compilation, une graine pour laquelle aucune
+	/// orthographe n'entre en collision. Une orthographe dupliquée est une
+	/// erreur de compilation.
+	pub const fn new<T>(spellings: &[(&str, T)]) -> Self {
+		assert!(spellings.len() < Self::EMPTY as usize);
+
+		let mut seed = 0x811c_9dc5;
+		'seed: loop {
+			let mut table = [Self::EMPTY; N];
+			let mut i = 0;
+			while i < spellings.len() {
+				let bytes = spellings[i].0.as_bytes();
+				let slot = hash::<N>(bytes, seed);
+				if table[slot] != Self::EMPTY {
+					let other = spellings[table[slot] as usize].0.as_bytes();
+					assert!(
+						!same_bytes(bytes, other),
+						"orthographe de mot-clé dupliquée"
+					);
+					seed = seed.wrapping_add(1);
+					continue 'seed;
+				}
+				table[slot] = i as u8;
+				i += 1;
+			}
+			return Self { seed, table };
+		}
+	}
+
+	/// L'index, dans `spellings`, de l'orthographe `bytes`.
+	#[inline]
+	pub fn lookup<T>(
+		&self,
+		spellings: &[(&str, T)],
+		bytes: &[u8],
+	) -> Option<usize> {
+		let index = self.table[hash::<N>(bytes, self.seed)];
+		(index != Self::EMPTY
+			&& spellings[index as usize].0.as_bytes() == bytes)
+			.then_some(index as usize)
+	}
+}
+
+// -------- //
+// Fonction //
+// -------- //
+
+/// Convertit, à la compilation, le nom d'un mot-clé en minuscules.
+pub const fn to_ascii_lowercase<const N: usize>(name: &str) -> [u8; N] {
+	let bytes = name.as_bytes();
+	let mut lowercase = [0; N];
+	let mut i = 0;
+	while i < N {
+		lowercase[i] = bytes[i].to_ascii_lowercase();
+		i += 1;
+	}
+	lowercase
+}
+
+/// La longueur de la plus longue orthographe.
+pub const fn max_len<T>(spellings: &[(&str, T)]) -> usize {
+	let mut max = 0;
+	let mut i = 0;
+	while i < spellings.len() {
+		if spellings[i].0.len() > max {
+			max = spellings[i].0.len();
+		}
+		i += 1;
+	}
+	max
+}
+
+/// La taille d'une table de hachage parfaite : au moins deux emplacements
+/// par orthographe.
+pub const fn table_size(len: usize) -> usize {
+	(len * 2).next_power_of_two()
+}
+
+/// Fonction de hachage FNV-1a, dont la base est remplacée par `seed`.
+const fn hash<const N: usize>(bytes: &[u8], seed: u32) -> usize {
+	let mut h = seed;
+	let mut i = 0;
+	while i < bytes.len() {
+		h = (h ^ bytes[i] as u32).wrapping_mul(0x0100_0193);
+		i += 1;
+	}
+	(h ^ (h >> 16)) as usize & (N - 1)
+}
+
+const fn same_bytes(a: &[u8], b: &[u8]) -> bool {
+	if a.len() != b.len() {
+		return false;
+	}
+	let mut i = 0;
+	while i < a.len() {
+		if a[i] != b[i] {
+			return false;
+		}
+		i += 1;
+	}
+	true
+}
+
+// ---- //
+// Test //
+// ---- //
+
+#[cfg(test)]
+mod tests {
+	mod css {
+		crate::Kword! {
+			- Media    as media | medias : Module
+			- Supports as supports
+		}
+	}
+
+	use self::css::Keyword;
+	use crate::keyword::KeywordCategory;
+
+	#[test]
+	fn test_kword_macro() {
+		assert_eq!(Keyword::MAX_SPELLING_LEN, 8);
+		for (index, (spelling, keyword)) in
+			Keyword::SPELLINGS.iter().enumerate()
+		{
+			assert_eq!(
+				Keyword::spelling_index(spelling.as_bytes()),
+				Some(index)
+			);
+			assert_eq!(spelling.parse::<Keyword>().as_ref(), Ok(keyword));
+		}
+		assert_eq!(Keyword::spelling_index(b"import"), None);
+		assert_eq!(Keyword::Media.category(), Some(KeywordCategory::Module));
+		assert_eq!(Keyword::Supports.as_str(), "supports");
+		assert_eq!(Keyword::ALL, [Keyword::Media, Keyword::Supports]);
+		assert_eq!(Keyword::Media.aliases(), ["media", "medias"]);
+		assert_eq!(Keyword::Supports.canonical(), "supports");
+
+		let id = Keyword::Media.id();
+		assert_eq!(id.as_str(), "media");
+		assert_eq!(id.aliases(), ["media", "medias"]);
+		assert_eq!(id.category(), Some(KeywordCategory::Module));
+		assert_eq!(Keyword::from_id(id), Some(Keyword::Media));
+		assert_eq!(Keyword::from_id(crate::keyword::Keyword::In.id()), None);
+	}
+}
@@ -0,0 +1,185 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+use super::{error::KeywordParseError, Keyword};
+
+// -------- //
+// Constant //
+// -------- //
+
+/// La longueur de la plus longue orthographe de mot-clé.
+const MAX_LEN: usize = Keyword::MAX_SPELLING_LEN;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Reconnaît un mot-clé point de code par point de code, pendant l'analyse
+/// d'un identifiant, sans construire de chaîne de caractères.
+///
+/// Les points de code lus sont conservés dans un tampon de la taille de la
+/// plus longue orthographe ; un point de code non ASCII, ou un identifiant
+/// trop long, écarte immédiatement tous les mots-clés. L'orthographe lue est
+/// ensuite cherchée dans la table de hachage parfaite générée, à la
+/// compilation, par le macro `Kword!`.
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+pub struct KeywordMatcher {
+	/// Les octets lus.
+	buffer: [u8; MAX_LEN],
+
+	/// Le nombre d'octets lus, ou `MAX_LEN + 1` lorsqu'aucun mot-clé ne
+	/// peut plus correspondre.
+	len: usize,
+
+	/// Les lettres ASCII lues sont converties en minuscules.
+	ascii_case_insensitive: bool,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl KeywordMatcher {
+	/// Aucun point de code n'a été lu.
+	pub const fn new() -> Self {
+		Self {
+			buffer: [0; MAX_LEN],
+			len: 0,
+			ascii_case_insensitive: false,
+		}
+	}
+
+	/// Aucun point de code n'a été lu ; les lettres ASCII seront converties
+	/// en minuscules avant d'être comparées aux orthographes, elles-mêmes en
+	/// minuscules : `IMPORT` et `Import` sont reconnus comme `import`.
+	pub const fn ignore_ascii_case() -> Self {
+		Self {
+			ascii_case_insensitive: true,
+			..Self::new()
+		}
+	}
+
+	/// Lit le point de code suivant de l'identifiant. Retourne `false`
+	/// lorsqu'aucun mot-clé ne peut plus correspondre aux points de code lus.
+	#[inline]
+	pub fn push(&mut self, ch: char) -> bool {
+		if self.len < MAX_LEN && ch.is_ascii() {
+			self.buffer[self.len] = if self.ascii_case_insensitive {
+				ch.to_ascii_lowercase() as u8
+			} else {
+				ch as u8
+			};
+			self.len += 1;
+			true
+		} else {
+			self.len = MAX_LEN + 1;
+			false
+		}
+	}
+
+	/// L'index, dans [Keyword::SPELLINGS], de l'orthographe lue.
+	#[inline]
+	pub(crate) fn index(&self) -> Option<usize> {
+		self.buffer
+			.get(..self.len)
+			.and_then(Keyword::spelling_index)
+	}
+
+	/// Le mot-clé dont une orthographe correspond exactement aux points de
+	/// code lus.
+	#[inline]
+	pub fn finish(&self) -> Option<Keyword> {
+		self.index().map(|index| Keyword::SPELLINGS[index].1)
+	}
+}
+
+impl Keyword {
+	/// Le mot-clé correspondant à une suite de points de code, sans
+	/// construire de chaîne de caractères.
+	pub fn from_chars(chars: impl IntoIterator<Item = char>) -> Option<Self> {
+		let mut matcher = KeywordMatcher::new();
+		for ch in chars {
+			if !matcher.push(ch) {
+				return None;
+			}
+		}
+		matcher.finish()
+	}
+
+	/// Le mot-clé correspondant à `input`, sans tenir compte de la casse
+	/// ASCII : `FUNCTION`, `Fn`. Les lettres non ASCII ne sont pas converties.
+	pub fn from_str_ignore_ascii_case(
+		input: &str,
+	) -> Result<Self, KeywordParseError> {
+		let mut matcher = KeywordMatcher::ignore_ascii_case();
+		input
+			.chars()
+			.all(|ch| matcher.push(ch))
+			.then(|| matcher.finish())
+			.flatten()
+			.ok_or_else(|| KeywordParseError::Unknown {
+				found: input.to_owned(),
+			})
+	}
+}
+
+impl Default for KeywordMatcher {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+// ---- //
+// Test //
+// ---- //
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_keyword_matcher() {
+		for (index, (spelling, keyword)) in
+			Keyword::SPELLINGS.iter().enumerate()
+		{
+			assert_eq!(
+				Keyword::spelling_index(spelling.as_bytes()),
+				Some(index)
+			);
+			assert_eq!(Keyword::from_chars(spelling.chars()), Some(*keyword));
+			assert_eq!(spelling.parse::<Keyword>().ok(), Some(*keyword));
+		}
+
+		assert_eq!(Keyword::from_chars("f".chars()), None);
+		assert_eq!(Keyword::from_chars("fun".chars()), None);
+		assert_eq!(Keyword::from_chars("functions".chars()), None);
+		assert_eq!(Keyword::from_chars("namespaces".chars()), None);
+		assert_eq!(Keyword::from_chars("léт".chars()), None);
+		assert_eq!(Keyword::from_chars("".chars()), None);
+
+		let mut matcher = KeywordMatcher::new();
+		assert!(matcher.push('i'));
+		assert_eq!(matcher.finish(), None);
+		assert!(matcher.push('n'));
+		assert_eq!(matcher.finish(), Some(Keyword::In));
+		assert!(matcher.push('x'));
+		assert_eq!(matcher.finish(), None);
+		assert!(!matcher.push('é'));
+		assert!(!matcher.push('n'));
+		assert_eq!(matcher.finish(), None);
+
+		let mut matcher = KeywordMatcher::ignore_ascii_case();
+		"ImPoRt".chars().for_each(|ch| _ = matcher.push(ch));
+		assert_eq!(matcher.finish(), Some(Keyword::Import));
+		assert_eq!(Keyword::from_chars("IMPORT".chars()), None);
+
+		assert_eq!(
+			Keyword::from_str_ignore_ascii_case("FUNC"),
+			Ok(Keyword::Function)
+		);
+		assert!(Keyword::from_str_ignore_ascii_case("ımport").is_err());
+	}
+}
@@ -2,45 +2,68 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
 
+mod category;
+/// Les mots-clés de CSS : les noms des règles `@` et les mots-clés CSS
+/// globaux. Aucun n'est réservé, `media` reste une valeur valide en dehors
+/// d'une règle `@media`.
+pub mod css;
 pub(crate) mod error;
-mod r#macro;
+mod id;
+#[doc(hidden)]
+pub mod r#macro;
+mod matcher;
+/// Les mots-clés de MSCSS : les noms des règles `@` de CSS, et les
+/// directives inspirées de SCSS (`@mixin`, `@include`, `@if`, `@each`).
+/// Aucun n'est réservé, `from` ou `to` restent des valeurs valides dans une
+/// règle `@keyframes`.
+pub mod mscss;
+mod output;
+mod table;
 
+pub use self::{
+	category::KeywordCategory,
+	error::KeywordParseError,
+	id::KeywordId,
+	matcher::KeywordMatcher,
+	output::KeywordOutput,
+	table::{KeywordEntry, KeywordKind, KeywordTable},
+};
 use crate::Kword;
 
 Kword! {
 	- Async    as async
 	- Await    as await
-	- Function as fn | func | function
-
-	- Let   as let
-	- Mut   as mut
-	- Const as const
-
-	- Loop     as loop
-	- For      as for
-	- In       as in
-	- While    as while
-	- Break    as break
-	- Continue as continue
-	- If       as if
-	- Else     as else
-	- Match    as match
-
-	- Yield  as yield
-	- Return as return
-
-	- Struct    as struct
-	- Enum      as enum
-	- Interface as interface
-
-	- Namespace as mod | namespace
-	- Impl      as impl
-
-	- Export as export
-	- Type   as type
-	- As     as as
-	- From   as from
-	- Import as import | use
+	- Function as fn | func | function : Declaration
+
+	- Let   as let   : Declaration
+	- Mut   as mut   : Declaration
+	- Const as const : Declaration
+
+	- Loop     as loop     : ControlFlow
+	- For      as for      : ControlFlow
+	- In       as in       : ControlFlow
+	- While    as while    : ControlFlow
+	- Break    as break    : ControlFlow
+	- Continue as continue : ControlFlow
+	- If       as if       : ControlFlow
+	- Else     as else     : ControlFlow
+	- Match    as match    : ControlFlow
+
+	- Yield  as yield  : ControlFlow
+	- Return as return : ControlFlow
+
+	- Struct    as struct    : Declaration
+	- Enum      as enum      : Declaration
+	- Interface as interface : Declaration
+
+	- Namespace as mod | namespace : Module
+	- Impl      as impl            : Declaration
+
+	- Export as export       : Module
+	- Type   as type         : Declaration
+	- As     as as           : Module
+	- From   as from         : Module
+	- Import as import | use : Module
 }
 
 #[cfg(test)]
@@ -55,4 +78,31 @@ mod tests {
 
 		assert_eq!("export".parse::<Keyword>(), Ok(Keyword::Export));
 	}
+
+	#[test]
+	fn test_keyword_reflection() {
+		assert_eq!(Keyword::ALL.len(), 27);
+		assert_eq!(Keyword::ALL[0], Keyword::Async);
+
+		assert_eq!(Keyword::Function.aliases(), ["fn", "func", "function"]);
+		assert_eq!(Keyword::Function.canonical(), "fn");
+		assert_eq!(Keyword::Function.as_str(), "function");
+		assert_eq!(Keyword::Namespace.canonical(), "mod");
+		assert_eq!(Keyword::Import.to_string(), "import");
+
+		assert_eq!(
+			Keyword::Function.category(),
+			Some(KeywordCategory::Declaration)
+		);
+		assert_eq!(
+			Keyword::Return.category(),
+			Some(KeywordCategory::ControlFlow)
+		);
+		assert_eq!(Keyword::Import.category(), Some(KeywordCategory::Module));
+		assert_eq!(Keyword::Await.category(), None);
+
+		for keyword in Keyword::ALL {
+			assert_eq!(keyword.canonical().parse::<Keyword>(), Ok(*keyword));
+		}
+	}
 }
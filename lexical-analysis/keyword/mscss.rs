@@ -0,0 +1,30 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+use crate::Kword;
+
+Kword! {
+	- Import  as import | use : Module
+	- Forward as forward      : Module
+
+	- Media     as media     : ControlFlow
+	- Supports  as supports  : ControlFlow
+	- Keyframes as keyframes : Declaration
+
+	- Mixin    as mixin    : Declaration
+	- Include  as include
+	- Extend   as extend
+	- Function as function : Declaration
+	- Return   as return   : ControlFlow
+
+	- If      as if      : ControlFlow
+	- Else    as else    : ControlFlow
+	- Each    as each    : ControlFlow
+	- For     as for     : ControlFlow
+	- In      as in      : ControlFlow
+	- From    as from    : ControlFlow
+	- Through as through : ControlFlow
+	- To      as to      : ControlFlow
+	- While   as while   : ControlFlow
+}
@@ -0,0 +1,26 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+use location::Span;
+
+use super::KeywordId;
+
+// --------- //
+// Structure //
+// --------- //
+
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+pub struct KeywordOutput {
+	/// Le mot-clé analysé.
+	pub keyword: KeywordId,
+
+	/// L'orthographe du mot-clé écrite dans le code source : `func` pour
+	/// [Keyword::Function](super::Keyword::Function).
+	pub spelling: String,
+
+	/// La portion du code source occupée par le mot-clé.
+	pub span: Span,
+}
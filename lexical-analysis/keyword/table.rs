@@ -0,0 +1,330 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+use std::{borrow::Cow, collections::HashMap};
+
+use super::{Keyword, KeywordId, KeywordMatcher};
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Une entrée d'une [table de mots-clés](KeywordTable).
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+pub struct KeywordEntry {
+	/// Le mot-clé, de n'importe quel langage.
+	pub keyword: KeywordId,
+
+	/// Le mot-clé est réservé, ou contextuel.
+	pub kind: KeywordKind,
+}
+
+/// Table des mots-clés d'un langage, construite à l'exécution.
+///
+/// Chaque orthographe est associée à un [mot-clé](KeywordId) réservé ou
+/// contextuel, de MikuScript ou d'un autre langage. Un mot-clé réservé produit un jeton
+/// [Token::Keyword](crate::Token::Keyword) ; un mot-clé contextuel produit un
+/// [identifiant](crate::identifier::IdentifierOutput) accompagné du mot-clé
+/// qu'il peut désigner, le parseur décide selon le contexte.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+pub struct KeywordTable {
+	/// Les entrées des orthographes des mots-clés de MikuScript, indexées
+	/// comme [Keyword::SPELLINGS] : l'analyseur y accède directement à partir
+	/// du [KeywordMatcher].
+	spellings: Box<[Option<KeywordEntry>]>,
+
+	/// Les entrées des autres orthographes.
+	custom: HashMap<String, KeywordEntry>,
+
+	/// Les orthographes sont insensibles à la casse ASCII : elles sont
+	/// converties en minuscules, comme les identifiants recherchés.
+	ascii_case_insensitive: bool,
+}
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+/// Le type d'un mot-clé.
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+pub enum KeywordKind {
+	/// Le mot-clé ne peut pas être utilisé comme identifiant : `fn`, `let`.
+	Reserved,
+
+	/// Le mot-clé n'en est un que dans certains contextes, il reste un
+	/// identifiant ailleurs : `from`, `type`, `as`.
+	Contextual,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl KeywordEntry {
+	/// Entrée d'un mot-clé réservé.
+	pub const fn reserved(keyword: KeywordId) -> Self {
+		Self {
+			keyword,
+			kind: KeywordKind::Reserved,
+		}
+	}
+
+	/// Entrée d'un mot-clé contextuel.
+	pub const fn contextual(keyword: KeywordId) -> Self {
+		Self {
+			keyword,
+			kind: KeywordKind::Contextual,
+		}
+	}
+
+	/// Le mot-clé est réservé.
+	pub fn is_reserved(&self) -> bool {
+		self.kind == KeywordKind::Reserved
+	}
+}
+
+impl KeywordTable {
+	/// Table vide : aucun mot n'est un mot-clé.
+	pub fn new() -> Self {
+		Self {
+			spellings: vec![None; Keyword::SPELLINGS.len()].into(),
+			custom: HashMap::new(),
+			ascii_case_insensitive: false,
+		}
+	}
+
+	/// Table vide, insensible à la casse ASCII : les orthographes ajoutées,
+	/// et recherchées, sont converties en minuscules. `Media` est alors
+	/// trouvé à partir de l'identifiant `MEDIA`, normalisé en `media`.
+	pub fn ignore_ascii_case() -> Self {
+		Self::new().into_ascii_case_insensitive()
+	}
+
+	/// Rend la table insensible à la casse ASCII : les orthographes déjà
+	/// ajoutées, et celles ajoutées ensuite, sont converties en minuscules.
+	pub fn into_ascii_case_insensitive(mut self) -> Self {
+		self.ascii_case_insensitive = true;
+		self.custom = self
+			.custom
+			.into_iter()
+			.map(|(spelling, entry)| (spelling.to_ascii_lowercase(), entry))
+			.collect();
+		self
+	}
+
+	/// L'orthographe, convertie en minuscules lorsque la table est
+	/// insensible à la casse ASCII.
+	fn normalize<'a>(&self, spelling: &'a str) -> Cow<'a, str> {
+		if self.ascii_case_insensitive
+			&& spelling.bytes().any(|byte| byte.is_ascii_uppercase())
+		{
+			Cow::Owned(spelling.to_ascii_lowercase())
+		} else {
+			Cow::Borrowed(spelling)
+		}
+	}
+
+	/// Ajoute, ou remplace, l'orthographe `spelling` d'un mot-clé.
+	pub fn insert(&mut self, spelling: impl Into<String>, entry: KeywordEntry) {
+		let mut spelling = spelling.into();
+		if self.ascii_case_insensitive {
+			spelling.make_ascii_lowercase();
+		}
+		match Keyword::spelling_index(spelling.as_bytes()) {
+			| Some(index) => self.spellings[index] = Some(entry),
+			| None => {
+				self.custom.insert(spelling, entry);
+			}
+		}
+	}
+
+	/// Ajoute toutes les orthographes ([KeywordId::aliases]) d'un mot-clé.
+	pub fn insert_aliases(&mut self, entry: KeywordEntry) {
+		for alias in entry.keyword.aliases() {
+			self.insert(*alias, entry);
+		}
+	}
+
+	/// Retire l'orthographe `spelling` de la table.
+	pub fn remove(&mut self, spelling: &str) -> Option<KeywordEntry> {
+		let spelling = self.normalize(spelling);
+		match Keyword::spelling_index(spelling.as_bytes()) {
+			| Some(index) => self.spellings[index].take(),
+			| None => self.custom.remove(spelling.as_ref()),
+		}
+	}
+
+	/// Le mot-clé correspondant à l'orthographe `spelling`.
+	#[inline]
+	pub fn get(&self, spelling: &str) -> Option<KeywordEntry> {
+		let spelling = self.normalize(spelling);
+		match Keyword::spelling_index(spelling.as_bytes()) {
+			| Some(index) => self.spellings[index],
+			| None => self.custom.get(spelling.as_ref()).copied(),
+		}
+	}
+
+	/// Le mot-clé correspondant à un identifiant déjà lu par `matcher`. La
+	/// chaîne `spelling` n'est consultée que pour les orthographes qui ne
+	/// sont pas dans [Keyword::SPELLINGS] ; elle doit déjà être en
+	/// minuscules lorsque la table est insensible à la casse ASCII.
+	#[inline]
+	pub fn get_matched(
+		&self,
+		matcher: &KeywordMatcher,
+		spelling: &str,
+	) -> Option<KeywordEntry> {
+		match matcher.index() {
+			| Some(index) => self.spellings[index],
+			| None if self.custom.is_empty() => None,
+			| None => self.custom.get(spelling).copied(),
+		}
+	}
+
+	/// Le nombre d'orthographes de la table.
+	pub fn len(&self) -> usize {
+		self.spellings.iter().flatten().count() + self.custom.len()
+	}
+
+	/// La table ne contient aucun mot-clé.
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl Default for KeywordTable {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl From<&[KeywordEntry]> for KeywordTable {
+	/// Table contenant toutes les orthographes des mots-clés `entries`.
+	fn from(entries: &[KeywordEntry]) -> Self {
+		let mut table = Self::new();
+		for entry in entries {
+			table.insert_aliases(*entry);
+		}
+		table
+	}
+}
+
+// ---- //
+// Test //
+// ---- //
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::keyword::{css, mscss};
+
+	#[test]
+	fn test_keyword_table() {
+		let mut table = KeywordTable::from(
+			&[
+				KeywordEntry::reserved(Keyword::Function.id()),
+				KeywordEntry::contextual(Keyword::From.id()),
+			][..],
+		);
+
+		assert_eq!(table.len(), 4);
+		assert_eq!(
+			table.get("func"),
+			Some(KeywordEntry::reserved(Keyword::Function.id()))
+		);
+		assert_eq!(
+			table.get("from"),
+			Some(KeywordEntry::contextual(Keyword::From.id()))
+		);
+		assert_eq!(table.get("let"), None);
+
+		table.insert("def", KeywordEntry::reserved(Keyword::Function.id()));
+		table.remove("func");
+		assert!(table.get("def").is_some_and(|entry| entry.is_reserved()));
+		assert_eq!(table.get("func"), None);
+		assert_eq!(table.len(), 4);
+
+		let mut matcher = KeywordMatcher::new();
+		"def".chars().for_each(|ch| _ = matcher.push(ch));
+		assert_eq!(
+			table.get_matched(&matcher, "def"),
+			Some(KeywordEntry::reserved(Keyword::Function.id()))
+		);
+		let mut matcher = KeywordMatcher::new();
+		"from".chars().for_each(|ch| _ = matcher.push(ch));
+		assert_eq!(
+			table.get_matched(&matcher, "from"),
+			Some(KeywordEntry::contextual(Keyword::From.id()))
+		);
+	}
+
+	#[test]
+	fn test_keyword_table_languages() {
+		// NOTE: `from` est un mot-clé de MikuScript et de MSCSS : l'entrée
+		// désigne le mot-clé de MSCSS.
+		let mut table = KeywordTable::new();
+		table.insert_aliases(KeywordEntry::contextual(
+			mscss::Keyword::From.id(),
+		));
+		table
+			.insert_aliases(KeywordEntry::reserved(mscss::Keyword::Mixin.id()));
+		let entry = table.get("from").unwrap();
+		assert_eq!(
+			mscss::Keyword::from_id(entry.keyword),
+			Some(mscss::Keyword::From)
+		);
+		assert_eq!(Keyword::from_id(entry.keyword), None);
+		assert_ne!(entry.keyword, Keyword::From.id());
+		assert_eq!(
+			table.get("mixin"),
+			Some(KeywordEntry::reserved(mscss::Keyword::Mixin.id()))
+		);
+
+		let mut matcher = KeywordMatcher::new();
+		"mixin".chars().for_each(|ch| _ = matcher.push(ch));
+		assert_eq!(
+			table.get_matched(&matcher, "mixin"),
+			Some(KeywordEntry::reserved(mscss::Keyword::Mixin.id()))
+		);
+	}
+
+	#[test]
+	fn test_keyword_table_ascii_case_insensitive() {
+		let mut table = KeywordTable::new();
+		table.insert(
+			"Media",
+			KeywordEntry::contextual(css::Keyword::Media.id()),
+		);
+		assert_eq!(table.get("media"), None);
+		let mut table = table.into_ascii_case_insensitive();
+		assert!(table.get("media").is_some());
+		assert!(table.get("MEDIA").is_some());
+		table.insert(
+			"SUPPORTS",
+			KeywordEntry::contextual(css::Keyword::Supports.id()),
+		);
+		assert!(table.get("supports").is_some());
+		assert!(table.remove("Supports").is_some());
+		assert_eq!(table.len(), 1);
+
+		let mut table = KeywordTable::ignore_ascii_case();
+		table.insert_aliases(KeywordEntry::reserved(css::Keyword::Import.id()));
+		let mut matcher = KeywordMatcher::ignore_ascii_case();
+		"Import".chars().for_each(|ch| _ = matcher.push(ch));
+		assert_eq!(
+			table.get_matched(&matcher, "import"),
+			Some(KeywordEntry::reserved(css::Keyword::Import.id()))
+		);
+	}
+}
@@ -15,9 +15,25 @@ mod io;
 pub mod keyword;
 #[cfg(feature = "literal")]
 pub mod literal;
+pub mod profile;
+mod recovery;
+mod token;
+mod tokenizer;
 
 pub mod prelude {
-	pub use super::{algorithms::TokenizerAlgorithms, error::LexicalError};
+	pub use super::{
+		algorithms::TokenizerAlgorithms,
+		error::LexicalError,
+		recovery::{
+			Diagnostic, ErrorToken, RecoveredTokens, Resynchronization,
+			Severity, TokenizerRecovery,
+		},
+	};
 }
 
-pub use self::io::{Input, ParseState};
+pub use self::{
+	io::{Input, ParseState},
+	profile::LexerProfile,
+	token::{Token, TokenKind},
+	tokenizer::Tokenizer,
+};
@@ -4,7 +4,7 @@
 
 use core::fmt;
 
-use location::Location;
+use location::Span;
 
 // --------- //
 // Structure //
@@ -15,7 +15,7 @@ use location::Location;
 #[derive(PartialEq, Eq)]
 pub struct BoolOutput {
 	pub value: Bool,
-	pub location: Location,
+	pub span: Span,
 }
 
 // ----------- //
@@ -0,0 +1,47 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+use location::Span;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Un octet, délimité par `b'` et `'`.
+///
+/// Exemple: `b'x'`, `b'\n'`, `b'\xFF'`.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+pub struct ByteOutput {
+	/// L'octet, la séquence d'échappement étant remplacée par l'octet
+	/// qu'elle représente.
+	pub value: u8,
+
+	/// Le texte source de l'octet, tel qu'il a été écrit. Les symboles de
+	/// début et de fin ne sont pas inclus.
+	pub raw: String,
+
+	/// La portion du code source occupée par l'octet.
+	pub span: Span,
+}
+
+/// Une chaîne d'octets, délimitée par `b"` et `"`.
+///
+/// Exemple: `b"GET / HTTP/1.1\r\n"`, `b"\x00\xFF"`.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+pub struct ByteStringOutput {
+	/// Les octets, les séquences d'échappement étant remplacées par les
+	/// octets qu'elles représentent.
+	pub value: Vec<u8>,
+
+	/// Le texte source de la chaîne d'octets, tel qu'il a été écrit. Les
+	/// symboles de début et de fin ne sont pas inclus.
+	pub raw: String,
+
+	/// La portion du code source occupée par la chaîne d'octets.
+	pub span: Span,
+}
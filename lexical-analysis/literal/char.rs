@@ -0,0 +1,28 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+use location::Span;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Un caractère, délimité par des U+0027 APOSTROPHE (').
+///
+/// Exemple: `'C'`, `'\n'`, `'\u{1F600}'`.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+pub struct CharOutput {
+	/// Le caractère, la séquence d'échappement étant remplacée par le
+	/// caractère qu'elle représente.
+	pub value: char,
+
+	/// Le texte source du caractère, tel qu'il a été écrit. Les symboles de
+	/// début et de fin ne sont pas inclus.
+	pub raw: String,
+
+	/// La portion du code source occupée par le caractère.
+	pub span: Span,
+}
@@ -0,0 +1,125 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+use core::fmt;
+
+use location::Span;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Un nombre CSS, `<number-token>` de CSS Syntax Level 3.
+///
+/// Exemple: `10`, `-3e2`, `+.5`.
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+pub struct NumberOutput {
+	/// Le signe écrit devant le nombre.
+	pub sign: Option<NumericSign>,
+
+	/// Le drapeau de type du nombre.
+	pub ty: NumberType,
+
+	/// La valeur du nombre, signe compris.
+	pub value: f64,
+
+	/// La portion du code source occupée par le nombre.
+	pub span: Span,
+}
+
+/// Un pourcentage CSS, `<percentage-token>` de CSS Syntax Level 3.
+///
+/// Exemple: `50%`, `-12.5%`.
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+pub struct PercentageOutput {
+	/// Le nombre précédant le U+0025 PERCENTAGE SIGN (%).
+	pub number: NumberOutput,
+
+	/// La portion du code source occupée par le pourcentage.
+	pub span: Span,
+}
+
+/// Une dimension CSS, `<dimension-token>` de CSS Syntax Level 3.
+///
+/// Exemple: `10px`, `1.5em`, `-3e2deg`.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+pub struct DimensionOutput {
+	/// Le nombre précédant l'unité.
+	pub number: NumberOutput,
+
+	/// L'unité, un identifiant dont les séquences d'échappement ont été
+	/// remplacées par les points de code qu'elles représentent.
+	pub unit: String,
+
+	/// La portion du code source occupée par la dimension.
+	pub span: Span,
+}
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+/// Le signe écrit devant un nombre CSS.
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+pub enum NumericSign {
+	/// U+002B PLUS SIGN (+)
+	Plus,
+
+	/// U+002D HYPHEN-MINUS (-)
+	Minus,
+}
+
+/// Le drapeau de type d'un nombre CSS.
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+pub enum NumberType {
+	/// Nombre sans partie décimale ni exposant : `10`, `-3`.
+	Integer,
+
+	/// Nombre avec une partie décimale ou un exposant : `1.5`, `3e2`.
+	Number,
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+/// Les valeurs sont comparées bit à bit : `NaN` n'étant jamais produit par
+/// l'analyse, l'égalité reste réflexive.
+impl PartialEq for NumberOutput {
+	fn eq(&self, other: &Self) -> bool {
+		self.sign == other.sign
+			&& self.ty == other.ty
+			&& self.value.to_bits() == other.value.to_bits()
+			&& self.span == other.span
+	}
+}
+
+impl Eq for NumberOutput {}
+
+impl fmt::Display for NumberOutput {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "number")
+	}
+}
+
+impl fmt::Display for PercentageOutput {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "percentage")
+	}
+}
+
+impl fmt::Display for DimensionOutput {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "dimension-{}", self.unit)
+	}
+}
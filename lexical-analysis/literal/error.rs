@@ -4,6 +4,203 @@
 
 use core::fmt;
 
+use locale::{LocalizedMessage, Message};
+use location::Span;
+
+use crate::profile::NumericSuffix;
+
+// -------- //
+// Constant //
+// -------- //
+
+const MESSAGES: &[Message] = &[
+	Message {
+		code: "integer-is-not",
+		en: "The character '{found}' is not a numeric code point.",
+		fr: "Le caractère '{found}' n'est pas un point de code numérique.",
+	},
+	Message {
+		code: "integer-overflow",
+		en: "The number at position '{span}' is too large to be represented.",
+		fr: "Le nombre en position '{span}' est trop grand pour être \
+		     représenté.",
+	},
+	Message {
+		code: "integer-misplaced-separator",
+		en: "The digit separator at position '{span}' must be placed between \
+		     two digits.",
+		fr: "Le séparateur de chiffres en position '{span}' doit être placé \
+		     entre deux chiffres.",
+	},
+	Message {
+		code: "integer-invalid-suffix",
+		en: "The suffix at position '{span}' is not a valid type for this \
+		     number.",
+		fr: "Le suffixe en position '{span}' n'est pas un type valide pour ce \
+		     nombre.",
+	},
+	Message {
+		code: "integer-invalid-digit",
+		en: "The digit '{found}' is not valid in base {radix} at position \
+		     '{span}'.",
+		fr: "Le chiffre '{found}' n'est pas valide en base {radix} en \
+		     position '{span}'.",
+	},
+	Message {
+		code: "integer-missing-digits",
+		en: "The prefix at position '{span}' must be followed by at least one \
+		     digit in base {radix}.",
+		fr: "Le préfixe en position '{span}' doit être suivi d'au moins un \
+		     chiffre en base {radix}.",
+	},
+	Message {
+		code: "integer-leading-zero",
+		en: "A decimal number cannot start with a zero at position '{span}'.",
+		fr: "Un nombre décimal ne peut pas commencer par un zéro en position \
+		     '{span}'.",
+	},
+	Message {
+		code: "integer-multiple-decimal-points",
+		en: "The number contains a second decimal point at position '{span}'.",
+		fr: "Le nombre contient une deuxième virgule en position '{span}'.",
+	},
+	Message {
+		code: "integer-out-of-range",
+		en: "The number at position '{span}' does not fit in the type \
+		     '{suffix}'.",
+		fr: "Le nombre en position '{span}' ne tient pas dans le type \
+		     '{suffix}'.",
+	},
+	Message {
+		code: "float-overflow",
+		en: "The floating-point number at position '{span}' is too large to \
+		     be represented.",
+		fr: "Le nombre à virgule flottante en position '{span}' est trop \
+		     grand pour être représenté.",
+	},
+	Message {
+		code: "float-underflow",
+		en: "The floating-point number at position '{span}' is too small to \
+		     be represented, its value is zero.",
+		fr: "Le nombre à virgule flottante en position '{span}' est trop \
+		     petit pour être représenté, sa valeur est zéro.",
+	},
+	Message {
+		code: "float-precision-loss",
+		en: "The number at position '{span}' cannot be represented exactly, \
+		     some digits are lost.",
+		fr: "Le nombre en position '{span}' ne peut pas être représenté \
+		     exactement, des chiffres sont perdus.",
+	},
+	Message {
+		code: "char-is-not",
+		en: "The character '{found}' does not start a character literal.",
+		fr: "Le caractère '{found}' ne commence pas un caractère littéral.",
+	},
+	Message {
+		code: "char-empty",
+		en: "The character literal is empty at position '{span}'.",
+		fr: "Le caractère littéral est vide en position '{span}'.",
+	},
+	Message {
+		code: "char-too-many",
+		en: "The character literal contains more than one character at \
+		     position '{span}'.",
+		fr: "Le caractère littéral contient plus d'un caractère en position \
+		     '{span}'.",
+	},
+	Message {
+		code: "char-unterminated",
+		en: "The character literal is not terminated at position \
+		     'L:{line},C:{column}'.",
+		fr: "Le caractère littéral n'est pas terminé en position \
+		     'L:{line},C:{column}'.",
+	},
+	Message {
+		code: "byte-is-not",
+		en: "The character '{found}' does not start a byte literal.",
+		fr: "Le caractère '{found}' ne commence pas un octet littéral.",
+	},
+	Message {
+		code: "byte-non-ascii",
+		en: "The character '{found}' is not ASCII, it is not allowed in a \
+		     byte literal at position '{span}'.",
+		fr: "Le caractère '{found}' n'est pas ASCII, il n'est pas autorisé \
+		     dans un octet littéral en position '{span}'.",
+	},
+	Message {
+		code: "byte-empty",
+		en: "The byte literal is empty at position '{span}'.",
+		fr: "L'octet littéral est vide en position '{span}'.",
+	},
+	Message {
+		code: "byte-too-many",
+		en: "The byte literal contains more than one byte at position \
+		     '{span}'.",
+		fr: "L'octet littéral contient plus d'un octet en position '{span}'.",
+	},
+	Message {
+		code: "byte-unterminated",
+		en: "The byte literal is not terminated at position \
+		     'L:{line},C:{column}'.",
+		fr: "L'octet littéral n'est pas terminé en position \
+		     'L:{line},C:{column}'.",
+	},
+	Message {
+		code: "string-is-not",
+		en: "The character '{found}' is not valid for a string.",
+		fr: "Le caractère '{found}' n'est valide pour une chaîne de \
+		     caractères.",
+	},
+	Message {
+		code: "string-unescaped-newline",
+		en: "The string at position '{span}' contains an unescaped line \
+		     break.",
+		fr: "La chaîne de caractères en position '{span}' contient un saut \
+		     de ligne non échappé.",
+	},
+	Message {
+		code: "string-unterminated",
+		en: "The string is not terminated at position 'L:{line},C:{column}'.",
+		fr: "La chaîne de caractères n'est pas terminée en position \
+		     'L:{line},C:{column}'.",
+	},
+	Message {
+		code: "string-template-unterminated",
+		en: "The template string is not terminated at position \
+		     'L:{line},C:{column}'.",
+		fr: "La chaîne de caractères modèle n'est pas terminée en position \
+		     'L:{line},C:{column}'.",
+	},
+	Message {
+		code: "string-raw-unterminated",
+		en: "The raw string opened with {hashes} '#' is not terminated at \
+		     position 'L:{line},C:{column}'.",
+		fr: "La chaîne de caractères brute ouverte avec {hashes} '#' n'est \
+		     pas terminée en position 'L:{line},C:{column}'.",
+	},
+	Message {
+		code: "string-invalid-escape",
+		en: "Invalid escape sequence '\\{found}' at position '{span}'.",
+		fr: "Séquence d'échappement '\\{found}' invalide en position \
+		     '{span}'.",
+	},
+	Message {
+		code: "string-out-of-range-codepoint",
+		en: "The escaped code point '{value}' is out of range at position \
+		     '{span}'.",
+		fr: "Le point de code échappé '{value}' est hors limite en position \
+		     '{span}'.",
+	},
+	Message {
+		code: "string-lone-surrogate",
+		en: "The escaped code point '{value}' is a lone surrogate at \
+		     position '{span}'.",
+		fr: "Le point de code échappé '{value}' est un substitut isolé en \
+		     position '{span}'.",
+	},
+];
+
 // ----------- //
 // Énumération //
 // ----------- //
@@ -15,8 +212,63 @@ pub enum LiteralParseError {
 	/// Erreur lors de l'analyse lexicale d'un nombre.
 	Integer(IntegerParseError),
 
+	/// Erreur lors de l'analyse de la valeur d'un nombre à virgule
+	/// flottante.
+	Float(FloatParseError),
+
+	/// Erreur lors de l'analyse lexicale d'un caractère.
+	Char(CharParseError),
+
 	/// Erreur lors de l'analyse lexicale d'une chaîne de caractères.
 	String(StringParseError),
+
+	/// Erreur lors de l'analyse lexicale d'un octet ou d'une chaîne
+	/// d'octets.
+	Byte(ByteParseError),
+}
+
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+pub enum ByteParseError {
+	/// Il ne s'agit pas d'un octet, ni d'une chaîne d'octets.
+	IsNot { found: char },
+
+	/// Un caractère non ASCII a été rencontré.
+	NonAscii { found: char, span: Span },
+
+	/// L'octet est vide : `b''`.
+	Empty { span: Span },
+
+	/// L'octet contient plus d'un octet : `b'ab'`.
+	TooManyCharacters { span: Span },
+
+	/// L'octet ou la chaîne d'octets n'est pas terminé avant la fin de la
+	/// ligne.
+	Unterminated { line: usize, column: usize },
+
+	/// La séquence d'échappement est invalide.
+	Escape(StringParseError),
+}
+
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+pub enum CharParseError {
+	/// Il ne s'agit pas d'un caractère.
+	IsNot { found: char },
+
+	/// Le caractère est vide : `''`.
+	Empty { span: Span },
+
+	/// Le caractère contient plus d'un caractère : `'ab'`.
+	TooManyCharacters { span: Span },
+
+	/// Le caractère n'est pas terminé avant la fin de la ligne.
+	Unterminated { line: usize, column: usize },
+
+	/// La séquence d'échappement du caractère est invalide.
+	Escape(StringParseError),
 }
 
 #[derive(Debug)]
@@ -25,6 +277,52 @@ pub enum LiteralParseError {
 pub enum IntegerParseError {
 	/// Il ne s'agit pas un point de code numérique.
 	IsNot { found: char }, // NOTE(phisyx): peut-être à retirer ?
+
+	/// Le nombre entier dépasse la valeur maximale d'un `u128`.
+	Overflow { span: Span },
+
+	/// Le nombre entier ne tient pas dans le type de son suffixe :
+	/// `256u8`, `0xFFi8`.
+	OutOfRange { suffix: NumericSuffix, span: Span },
+
+	/// Le séparateur U+005F LOW LINE (_) n'est pas placé entre deux
+	/// chiffres : `1__0`, `1_`, `0x_1`, `1_.0`.
+	MisplacedSeparator { span: Span },
+
+	/// Le suffixe est inconnu, ou ne correspond pas au nombre : `10u7`,
+	/// `2.0u8`, `0b1f32`.
+	InvalidSuffix { span: Span },
+
+	/// Le chiffre n'est pas valide dans la base du nombre : `0b102`, `0o9`.
+	InvalidDigit { found: char, radix: u32, span: Span },
+
+	/// Le préfixe n'est suivi d'aucun chiffre de sa base : `0x`, `0b`.
+	MissingDigits { radix: u32, span: Span },
+
+	/// Le nombre décimal commence par un zéro, et le profil ne l'autorise
+	/// pas : `08`.
+	LeadingZero { span: Span },
+
+	/// Le nombre contient plus d'une virgule : `1.2.3`.
+	MultipleDecimalPoints { span: Span },
+}
+
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+pub enum FloatParseError {
+	/// Le nombre dépasse la valeur maximale représentable : `f64`, ou `f32`
+	/// avec le suffixe `f32`.
+	Overflow { span: Span },
+
+	/// Le nombre n'est pas nul, mais sa valeur est arrondie à zéro :
+	/// `1e-400`. Il s'agit d'une erreur non fatale.
+	Underflow { span: Span },
+
+	/// Le nombre a plus de chiffres significatifs que `f64`, ou `f32` avec
+	/// le suffixe `f32`, ne peut en conserver. Il s'agit d'une erreur non
+	/// fatale.
+	PrecisionLoss { span: Span },
 }
 
 #[derive(Debug)]
@@ -34,61 +332,307 @@ pub enum StringParseError {
 	/// Il ne s'agit pas une chaîne de caractères.
 	IsNot { found: char }, // NOTE(phisyx): peut-être à retirer ?
 
-	/// La chaîne de caractère est mal formée.
-	BadString,
+	/// La chaîne de caractères contient un saut de ligne non échappé.
+	/// L'emplacement va du début de la chaîne au saut de ligne.
+	UnescapedNewline { span: Span },
 
 	/// La chaîne de caractères n'est pas terminée.
 	Unterminated { line: usize, column: usize },
+
+	/// La chaîne de caractères modèle n'est pas terminée. L'emplacement est
+	/// celui de son U+0060 GRAVE ACCENT (`) de début.
+	UnterminatedTemplate { line: usize, column: usize },
+
+	/// La chaîne de caractères brute n'est pas terminée. `hashes` est le
+	/// nombre de U+0023 NUMBER SIGN (#) de son début.
+	UnterminatedRaw {
+		hashes: usize,
+		line: usize,
+		column: usize,
+	},
+
+	/// La séquence d'échappement est invalide ou mal formée, par exemple
+	/// `\q`, `\x4` ou `\u{12`. `found` est le point de code suivant le
+	/// U+005C REVERSE SOLIDUS (\).
+	InvalidEscape { found: char, span: Span },
+
+	/// Le point de code échappé est hors limite : supérieur à `0x7F` pour
+	/// `\xHH` (`0xFF` dans un octet littéral), ou à `0x10FFFF` pour
+	/// `\u{...}`.
+	OutOfRangeCodePoint { value: u32, span: Span },
+
+	/// Le point de code échappé est un substitut (U+D800 à U+DFFF) isolé.
+	LoneSurrogate { value: u32, span: Span },
 }
 
 // -------------- //
 // Implémentation // -> Interface
 // -------------- //
 
+impl LocalizedMessage for LiteralParseError {
+	fn catalog(&self) -> &'static [Message] {
+		MESSAGES
+	}
+
+	fn code(&self) -> &'static str {
+		match self {
+			| Self::Integer(err) => err.code(),
+			| Self::Float(err) => err.code(),
+			| Self::Char(err) => err.code(),
+			| Self::String(err) => err.code(),
+			| Self::Byte(err) => err.code(),
+		}
+	}
+
+	fn placeholders(&self) -> Vec<(&'static str, String)> {
+		match self {
+			| Self::Integer(err) => err.placeholders(),
+			| Self::Float(err) => err.placeholders(),
+			| Self::Char(err) => err.placeholders(),
+			| Self::String(err) => err.placeholders(),
+			| Self::Byte(err) => err.placeholders(),
+		}
+	}
+}
+
+impl LocalizedMessage for ByteParseError {
+	fn catalog(&self) -> &'static [Message] {
+		MESSAGES
+	}
+
+	fn code(&self) -> &'static str {
+		match self {
+			| Self::IsNot { .. } => "byte-is-not",
+			| Self::NonAscii { .. } => "byte-non-ascii",
+			| Self::Empty { .. } => "byte-empty",
+			| Self::TooManyCharacters { .. } => "byte-too-many",
+			| Self::Unterminated { .. } => "byte-unterminated",
+			| Self::Escape(err) => err.code(),
+		}
+	}
+
+	fn placeholders(&self) -> Vec<(&'static str, String)> {
+		match self {
+			| Self::IsNot { found } => vec![("found", found.to_string())],
+			| Self::NonAscii { found, span } => {
+				vec![("found", found.to_string()), ("span", span.to_string())]
+			}
+			| Self::Empty { span } | Self::TooManyCharacters { span } => {
+				vec![("span", span.to_string())]
+			}
+			| Self::Unterminated { line, column } => {
+				vec![("line", line.to_string()), ("column", column.to_string())]
+			}
+			| Self::Escape(err) => err.placeholders(),
+		}
+	}
+}
+
+impl LocalizedMessage for CharParseError {
+	fn catalog(&self) -> &'static [Message] {
+		MESSAGES
+	}
+
+	fn code(&self) -> &'static str {
+		match self {
+			| Self::IsNot { .. } => "char-is-not",
+			| Self::Empty { .. } => "char-empty",
+			| Self::TooManyCharacters { .. } => "char-too-many",
+			| Self::Unterminated { .. } => "char-unterminated",
+			| Self::Escape(err) => err.code(),
+		}
+	}
+
+	fn placeholders(&self) -> Vec<(&'static str, String)> {
+		match self {
+			| Self::IsNot { found } => vec![("found", found.to_string())],
+			| Self::Empty { span } | Self::TooManyCharacters { span } => {
+				vec![("span", span.to_string())]
+			}
+			| Self::Unterminated { line, column } => {
+				vec![("line", line.to_string()), ("column", column.to_string())]
+			}
+			| Self::Escape(err) => err.placeholders(),
+		}
+	}
+}
+
+impl LocalizedMessage for IntegerParseError {
+	fn catalog(&self) -> &'static [Message] {
+		MESSAGES
+	}
+
+	fn code(&self) -> &'static str {
+		match self {
+			| Self::IsNot { .. } => "integer-is-not",
+			| Self::Overflow { .. } => "integer-overflow",
+			| Self::OutOfRange { .. } => "integer-out-of-range",
+			| Self::MisplacedSeparator { .. } => "integer-misplaced-separator",
+			| Self::InvalidSuffix { .. } => "integer-invalid-suffix",
+			| Self::InvalidDigit { .. } => "integer-invalid-digit",
+			| Self::MissingDigits { .. } => "integer-missing-digits",
+			| Self::LeadingZero { .. } => "integer-leading-zero",
+			| Self::MultipleDecimalPoints { .. } => {
+				"integer-multiple-decimal-points"
+			}
+		}
+	}
+
+	fn placeholders(&self) -> Vec<(&'static str, String)> {
+		match self {
+			| Self::IsNot { found } => vec![("found", found.to_string())],
+			| Self::Overflow { span }
+			| Self::MisplacedSeparator { span }
+			| Self::InvalidSuffix { span }
+			| Self::LeadingZero { span }
+			| Self::MultipleDecimalPoints { span } => {
+				vec![("span", span.to_string())]
+			}
+			| Self::InvalidDigit { found, radix, span } => {
+				vec![
+					("found", found.to_string()),
+					("radix", radix.to_string()),
+					("span", span.to_string()),
+				]
+			}
+			| Self::MissingDigits { radix, span } => {
+				vec![("radix", radix.to_string()), ("span", span.to_string())]
+			}
+			| Self::OutOfRange { suffix, span } => {
+				vec![
+					("suffix", suffix.as_str().to_owned()),
+					("span", span.to_string()),
+				]
+			}
+		}
+	}
+}
+
+impl LocalizedMessage for FloatParseError {
+	fn catalog(&self) -> &'static [Message] {
+		MESSAGES
+	}
+
+	fn code(&self) -> &'static str {
+		match self {
+			| Self::Overflow { .. } => "float-overflow",
+			| Self::Underflow { .. } => "float-underflow",
+			| Self::PrecisionLoss { .. } => "float-precision-loss",
+		}
+	}
+
+	fn placeholders(&self) -> Vec<(&'static str, String)> {
+		match self {
+			| Self::Overflow { span }
+			| Self::Underflow { span }
+			| Self::PrecisionLoss { span } => {
+				vec![("span", span.to_string())]
+			}
+		}
+	}
+}
+
+impl LocalizedMessage for StringParseError {
+	fn catalog(&self) -> &'static [Message] {
+		MESSAGES
+	}
+
+	fn code(&self) -> &'static str {
+		match self {
+			| Self::IsNot { .. } => "string-is-not",
+			| Self::UnescapedNewline { .. } => "string-unescaped-newline",
+			| Self::Unterminated { .. } => "string-unterminated",
+			| Self::UnterminatedTemplate { .. } => {
+				"string-template-unterminated"
+			}
+			| Self::UnterminatedRaw { .. } => "string-raw-unterminated",
+			| Self::InvalidEscape { .. } => "string-invalid-escape",
+			| Self::OutOfRangeCodePoint { .. } => {
+				"string-out-of-range-codepoint"
+			}
+			| Self::LoneSurrogate { .. } => "string-lone-surrogate",
+		}
+	}
+
+	fn placeholders(&self) -> Vec<(&'static str, String)> {
+		match self {
+			| Self::IsNot { found } => vec![("found", found.to_string())],
+			| Self::UnescapedNewline { span } => {
+				vec![("span", span.to_string())]
+			}
+			| Self::Unterminated { line, column }
+			| Self::UnterminatedTemplate { line, column } => {
+				vec![("line", line.to_string()), ("column", column.to_string())]
+			}
+			| Self::UnterminatedRaw {
+				hashes,
+				line,
+				column,
+			} => {
+				vec![
+					("hashes", hashes.to_string()),
+					("line", line.to_string()),
+					("column", column.to_string()),
+				]
+			}
+			| Self::InvalidEscape { found, span } => {
+				vec![("found", found.to_string()), ("span", span.to_string())]
+			}
+			| Self::OutOfRangeCodePoint { value, span }
+			| Self::LoneSurrogate { value, span } => {
+				vec![
+					("value", format!("U+{value:04X}")),
+					("span", span.to_string()),
+				]
+			}
+		}
+	}
+}
+
 impl fmt::Display for LiteralParseError {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		write!(
-			f,
-			"{}",
-			match self {
-				| Self::Integer(err) => err.to_string(),
-				| Self::String(err) => err.to_string(),
-			}
-		)
+		write!(f, "{}", self.message())
 	}
 }
 
 impl fmt::Display for IntegerParseError {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		write!(
-			f,
-			"{}",
-			match self {
-				| Self::IsNot { found } => format!(
-					"Le caractère '{}' n'est pas un point de code numérique.",
-					found
-				),
-			}
-		)
+		write!(f, "{}", self.message())
+	}
+}
+
+impl fmt::Display for FloatParseError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.message())
+	}
+}
+
+impl fmt::Display for ByteParseError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.message())
+	}
+}
+
+impl fmt::Display for CharParseError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.message())
 	}
 }
 
 impl fmt::Display for StringParseError {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		write!(
-            f,
-            "{}",
-            match self {
-                | Self::IsNot { found } => format!(
-                    "Le caractère '{}' n'est valide pour une chaîne de caractères.",
-                    found
-                ),
-                | Self::BadString => "La chaîne de caractères est mal formée.".to_owned(),
-                | Self::Unterminated { line, column } => format!(
-                    "La chaîne de caractères n'est pas terminée en position 'L:{},C:{}'.",
-                    line, column
-                ),
-            }
-        )
+		write!(f, "{}", self.message())
+	}
+}
+
+impl From<IntegerParseError> for LiteralParseError {
+	fn from(error: IntegerParseError) -> Self {
+		Self::Integer(error)
+	}
+}
+
+impl From<FloatParseError> for LiteralParseError {
+	fn from(error: FloatParseError) -> Self {
+		Self::Float(error)
 	}
 }
@@ -0,0 +1,90 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+use core::fmt;
+
+use location::Span;
+
+use crate::profile::NumericSuffix;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Un nombre à virgule flottante.
+///
+/// Exemple: `10.0`, `1.`, `.5`, `1e-9`, `2.5E+3`, `2.0f32`, `3f64`.
+#[derive(Debug)]
+#[derive(Clone)]
+pub struct FloatOutput {
+	/// La mantisse du nombre, sans ses séparateurs : `2.5` pour `2.5E+3`.
+	pub mantissa: String,
+
+	/// L'exposant du nombre : `-9` pour `1e-9`.
+	pub exponent: Option<i32>,
+
+	/// Le suffixe de type : `f32` pour `2.0f32`.
+	pub suffix: Option<NumericSuffix>,
+
+	/// La valeur du nombre.
+	pub value: f64,
+
+	/// La portion du code source occupée par le nombre.
+	pub span: Span,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl FloatOutput {
+	/// Vérifie que la valeur conserve tous les chiffres significatifs de la
+	/// mantisse et de l'exposant. Un nombre suffixé par `f32` est comparé à
+	/// sa valeur arrondie en `f32` : `16777217f32` vaut `16777216`.
+	pub fn is_lossless(&self) -> bool {
+		let (digits, position) = significant_digits(&self.mantissa);
+		let exponent = self.exponent.unwrap_or_default() as isize;
+		let value = match self.suffix {
+			| Some(NumericSuffix::F32) => (self.value as f32).to_string(),
+			| _ => self.value.to_string(),
+		};
+		(digits, position.saturating_add(exponent))
+			== significant_digits(&value)
+	}
+}
+
+/// Les chiffres significatifs d'un nombre décimal, sans les zéros de début
+/// et de fin, ainsi que la position de la virgule par rapport au premier
+/// chiffre significatif.
+fn significant_digits(decimal: &str) -> (String, isize) {
+	let (int, frac) = decimal.split_once('.').unwrap_or((decimal, ""));
+	let digits = format!("{int}{frac}");
+	let trimmed = digits.trim_start_matches('0');
+	let exponent = int.len() as isize - (digits.len() - trimmed.len()) as isize;
+	(trimmed.trim_end_matches('0').to_owned(), exponent)
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+/// Les valeurs sont comparées bit à bit : `NaN` n'étant jamais produit par
+/// l'analyse, l'égalité reste réflexive.
+impl PartialEq for FloatOutput {
+	fn eq(&self, other: &Self) -> bool {
+		self.mantissa == other.mantissa
+			&& self.exponent == other.exponent
+			&& self.suffix == other.suffix
+			&& self.value.to_bits() == other.value.to_bits()
+			&& self.span == other.span
+	}
+}
+
+impl Eq for FloatOutput {}
+
+impl fmt::Display for FloatOutput {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "numeric-float")
+	}
+}
@@ -4,7 +4,9 @@
 
 use core::fmt;
 
-use location::Location;
+use location::Span;
+
+use crate::profile::NumericSuffix;
 
 // --------- //
 // Structure //
@@ -14,8 +16,13 @@ use location::Location;
 #[derive(Clone)]
 #[derive(PartialEq, Eq)]
 pub struct IntegerOutput {
+	/// Les chiffres du nombre, sans leurs séparateurs.
 	pub integer: Integer,
-	pub location: Location,
+	/// Le suffixe de type : `u8` pour `10u8`.
+	pub suffix: Option<NumericSuffix>,
+	/// La valeur du nombre.
+	pub value: u128,
+	pub span: Span,
 }
 
 // ----------- //
@@ -28,7 +35,7 @@ pub struct IntegerOutput {
 pub enum Integer {
 	/// Nombre décimal.
 	///
-	/// Exemple: 71, 10.0, 1.463, ...
+	/// Exemple: 71, 1_000_000, ...
 	Decimal { int: String },
 
 	/// Nombre hexadécimal.
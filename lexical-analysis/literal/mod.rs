@@ -3,15 +3,30 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
 
 mod bool;
+mod byte;
+mod char;
+mod dimension;
 mod error;
+mod float;
 mod int;
 mod output;
 mod state;
 mod string;
+mod template;
 
 use core::fmt;
 
-pub use self::{bool::Bool, error::*, int::Integer, output::*, state::*};
+use location::Span;
+
+pub use self::{
+	bool::Bool,
+	dimension::{NumberType, NumericSign},
+	error::*,
+	int::Integer,
+	output::*,
+	state::*,
+	template::TemplatePart,
+};
 
 // ----------- //
 // Énumération //
@@ -21,16 +36,63 @@ pub use self::{bool::Bool, error::*, int::Integer, output::*, state::*};
 #[derive(Clone)]
 #[derive(PartialEq, Eq)]
 pub enum Literal {
-	/// Nombre.
+	/// Nombre entier.
 	Integer(IntegerOutput),
 
+	/// Nombre à virgule flottante.
+	Float(FloatOutput),
+
+	/// Nombre CSS, avec son signe et son drapeau de type.
+	Number(NumberOutput),
+
+	/// Pourcentage CSS.
+	Percentage(PercentageOutput),
+
+	/// Dimension CSS, un nombre suivi d'une unité.
+	Dimension(DimensionOutput),
+
+	/// Caractère.
+	Char(CharOutput),
+
 	/// Chaîne de caractères.
 	String(StringOutput),
 
+	/// Octet.
+	Byte(ByteOutput),
+
+	/// Chaîne d'octets.
+	ByteString(ByteStringOutput),
+
+	/// Morceau d'une chaîne de caractères modèle.
+	Template(TemplateOutput),
+
 	/// Booléen.
 	Bool(BoolOutput),
 }
 
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl Literal {
+	/// La portion du code source occupée par le littéral.
+	pub fn span(&self) -> Span {
+		match self {
+			| Self::Integer(int) => int.span,
+			| Self::Float(float) => float.span,
+			| Self::Number(number) => number.span,
+			| Self::Percentage(percentage) => percentage.span,
+			| Self::Dimension(dimension) => dimension.span,
+			| Self::Char(ch) => ch.span,
+			| Self::String(string) => string.span,
+			| Self::Byte(byte) => byte.span,
+			| Self::ByteString(bytes) => bytes.span,
+			| Self::Template(template) => template.span,
+			| Self::Bool(bool) => bool.span,
+		}
+	}
+}
+
 // -------------- //
 // Implémentation // -> Interface
 // -------------- //
@@ -39,7 +101,15 @@ impl fmt::Display for Literal {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		let l = match self {
 			| Literal::Integer(int) => int.to_string(),
+			| Literal::Float(float) => float.to_string(),
+			| Literal::Number(number) => number.to_string(),
+			| Literal::Percentage(percentage) => percentage.to_string(),
+			| Literal::Dimension(dimension) => dimension.to_string(),
+			| Literal::Char(_) => "char".to_owned(),
 			| Literal::String(_) => "string".to_owned(),
+			| Literal::Byte(_) => "byte".to_owned(),
+			| Literal::ByteString(_) => "byte-string".to_owned(),
+			| Literal::Template(template) => template.to_string(),
 			| Literal::Bool(_) => "bool".to_owned(),
 		};
 		write!(f, "{l}")
@@ -2,4 +2,13 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
 
-pub use super::{bool::BoolOutput, int::IntegerOutput, string::StringOutput};
+pub use super::{
+	bool::BoolOutput,
+	byte::{ByteOutput, ByteStringOutput},
+	char::CharOutput,
+	dimension::{DimensionOutput, NumberOutput, PercentageOutput},
+	float::FloatOutput,
+	int::IntegerOutput,
+	string::StringOutput,
+	template::TemplateOutput,
+};
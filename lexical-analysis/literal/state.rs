@@ -17,10 +17,16 @@ pub enum IntegerState {
 	DecimalInteger,
 	DecimalFloat,
 
+	/// L'exposant facultatif d'un nombre décimal : `e-9`, `E+3`.
+	Exponent,
+
 	Hexadecimal,
 	Binary,
 	Octal,
 
+	/// Le suffixe de type facultatif du nombre : `u8`, `f32`.
+	Suffix,
+
 	IsNot,
 }
 
@@ -31,6 +37,25 @@ pub enum StringState {
 
 	SingleQuoted,
 	DoubleQuoted,
+
+	/// Chaîne de caractères de plusieurs lignes, délimitée par trois
+	/// U+0022 QUOTATION MARK (`"""..."""`).
+	TripleQuoted,
+
+	/// Le préfixe `r` d'une chaîne de caractères brute a été consommé,
+	/// suivi de `hashes` points de code U+0023 NUMBER SIGN (#).
+	RawStart {
+		hashes: usize,
+	},
+
+	/// Chaîne de caractères brute, par exemple `r"..."` ou `r#"..."#` : les
+	/// séquences d'échappement ne sont pas interprétées, les guillemets et
+	/// les sauts de ligne sont autorisés. Elle se termine par un U+0022
+	/// QUOTATION MARK (") suivi de `hashes` points de code U+0023 NUMBER
+	/// SIGN (#).
+	Raw {
+		hashes: usize,
+	},
 }
 
 // -------------- //
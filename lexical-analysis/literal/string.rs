@@ -4,7 +4,7 @@
 
 use std::ops;
 
-use location::{Location, LocationInterface};
+use location::{LocationInterface, Span};
 
 // --------- //
 // Structure //
@@ -15,12 +15,17 @@ use location::{Location, LocationInterface};
 #[derive(Default)]
 #[derive(PartialEq, Eq)]
 pub struct StringOutput {
-	/// La chaîne de caractères analysée.
+	/// La valeur de la chaîne de caractères, les séquences d'échappement
+	/// étant remplacées par les caractères qu'elles représentent.
 	/// Les symboles de début et de fin ne sont pas inclus.
 	pub data: String,
 
-	/// L'emplacement de la chaîne de caractères dans la source.
-	pub location: Location,
+	/// Le texte source de la chaîne de caractères, tel qu'il a été écrit.
+	/// Les symboles de début et de fin ne sont pas inclus.
+	pub raw: String,
+
+	/// La portion du code source occupée par la chaîne de caractères.
+	pub span: Span,
 }
 
 // -------------- //
@@ -31,7 +36,8 @@ impl StringOutput {
 	/// Ajoute un caractère à la fin d'une chaîne de caractères.
 	pub fn append(&mut self, ch: char) {
 		self.data.push(ch);
-		self.location.increment_column();
+		self.raw.push(ch);
+		self.span.end.increment_column();
 	}
 }
 
@@ -0,0 +1,81 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+use core::fmt;
+
+use location::Span;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Un morceau d'une chaîne de caractères modèle (template string), délimitée
+/// par des U+0060 GRAVE ACCENT (`) et pouvant contenir des interpolations
+/// `${expression}`.
+///
+/// Exemple: `` `a ${b} c ${d} e` `` produit les morceaux `a ` (Head),
+/// ` c ` (Middle) et ` e` (Tail), les jetons des expressions `b` et `d`
+/// étant produits entre ces morceaux.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+pub struct TemplateOutput {
+	/// La position du morceau dans la chaîne de caractères modèle.
+	pub part: TemplatePart,
+
+	/// La valeur du morceau, les séquences d'échappement étant remplacées
+	/// par les caractères qu'elles représentent.
+	pub data: String,
+
+	/// Le texte source du morceau, tel qu'il a été écrit. Les délimiteurs
+	/// (`` ` ``, `${`, `}`) ne sont pas inclus.
+	pub raw: String,
+
+	/// La portion du code source occupée par le morceau, délimiteurs
+	/// compris.
+	pub span: Span,
+}
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+pub enum TemplatePart {
+	/// Chaîne de caractères modèle sans interpolation : `` `...` ``.
+	NoSubstitution,
+
+	/// Début d'une chaîne de caractères modèle : `` `...${ ``.
+	Head,
+
+	/// Morceau entre deux interpolations : `}...${`.
+	Middle,
+
+	/// Fin d'une chaîne de caractères modèle : `` }...` ``.
+	Tail,
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl fmt::Display for TemplateOutput {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.part.fmt(f)
+	}
+}
+
+impl fmt::Display for TemplatePart {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let s: &'static str = match self {
+			| Self::NoSubstitution => "no-substitution",
+			| Self::Head => "head",
+			| Self::Middle => "middle",
+			| Self::Tail => "tail",
+		};
+		write!(f, "template-{s}")
+	}
+}
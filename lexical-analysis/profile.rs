@@ -0,0 +1,772 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+use core::str;
+
+#[cfg(feature = "keyword")]
+use crate::keyword::{css, mscss, Keyword, KeywordEntry};
+
+// -------- //
+// Constant //
+// -------- //
+
+#[cfg(feature = "keyword")]
+/// Les mots-clés de MikuScript. `type`, `as` et `from` sont contextuels :
+/// ils restent utilisables comme identifiants.
+pub const MIKUSCRIPT_KEYWORDS: &[KeywordEntry] = &[
+	KeywordEntry::reserved(Keyword::Async.id()),
+	KeywordEntry::reserved(Keyword::Await.id()),
+	KeywordEntry::reserved(Keyword::Function.id()),
+	KeywordEntry::reserved(Keyword::Let.id()),
+	KeywordEntry::reserved(Keyword::Mut.id()),
+	KeywordEntry::reserved(Keyword::Const.id()),
+	KeywordEntry::reserved(Keyword::Loop.id()),
+	KeywordEntry::reserved(Keyword::For.id()),
+	KeywordEntry::reserved(Keyword::In.id()),
+	KeywordEntry::reserved(Keyword::While.id()),
+	KeywordEntry::reserved(Keyword::Break.id()),
+	KeywordEntry::reserved(Keyword::Continue.id()),
+	KeywordEntry::reserved(Keyword::If.id()),
+	KeywordEntry::reserved(Keyword::Else.id()),
+	KeywordEntry::reserved(Keyword::Match.id()),
+	KeywordEntry::reserved(Keyword::Yield.id()),
+	KeywordEntry::reserved(Keyword::Return.id()),
+	KeywordEntry::reserved(Keyword::Struct.id()),
+	KeywordEntry::reserved(Keyword::Enum.id()),
+	KeywordEntry::reserved(Keyword::Interface.id()),
+	KeywordEntry::reserved(Keyword::Namespace.id()),
+	KeywordEntry::reserved(Keyword::Impl.id()),
+	KeywordEntry::reserved(Keyword::Export.id()),
+	KeywordEntry::contextual(Keyword::Type.id()),
+	KeywordEntry::contextual(Keyword::As.id()),
+	KeywordEntry::contextual(Keyword::From.id()),
+	KeywordEntry::reserved(Keyword::Import.id()),
+];
+
+#[cfg(feature = "keyword")]
+/// Les mots-clés de MSCSS. Ils sont tous contextuels : `from`, `to` ou
+/// `media` restent des valeurs ou des noms de propriétés valides.
+pub const MSCSS_KEYWORDS: &[KeywordEntry] = &[
+	KeywordEntry::contextual(mscss::Keyword::Import.id()),
+	KeywordEntry::contextual(mscss::Keyword::Forward.id()),
+	KeywordEntry::contextual(mscss::Keyword::Media.id()),
+	KeywordEntry::contextual(mscss::Keyword::Supports.id()),
+	KeywordEntry::contextual(mscss::Keyword::Keyframes.id()),
+	KeywordEntry::contextual(mscss::Keyword::Mixin.id()),
+	KeywordEntry::contextual(mscss::Keyword::Include.id()),
+	KeywordEntry::contextual(mscss::Keyword::Extend.id()),
+	KeywordEntry::contextual(mscss::Keyword::Function.id()),
+	KeywordEntry::contextual(mscss::Keyword::Return.id()),
+	KeywordEntry::contextual(mscss::Keyword::If.id()),
+	KeywordEntry::contextual(mscss::Keyword::Else.id()),
+	KeywordEntry::contextual(mscss::Keyword::Each.id()),
+	KeywordEntry::contextual(mscss::Keyword::For.id()),
+	KeywordEntry::contextual(mscss::Keyword::In.id()),
+	KeywordEntry::contextual(mscss::Keyword::From.id()),
+	KeywordEntry::contextual(mscss::Keyword::Through.id()),
+	KeywordEntry::contextual(mscss::Keyword::To.id()),
+	KeywordEntry::contextual(mscss::Keyword::While.id()),
+];
+
+#[cfg(feature = "keyword")]
+/// Les mots-clés de CSS. Ils sont tous contextuels, CSS n'a aucun mot
+/// réservé.
+pub const CSS_KEYWORDS: &[KeywordEntry] = &[
+	KeywordEntry::contextual(css::Keyword::Charset.id()),
+	KeywordEntry::contextual(css::Keyword::Import.id()),
+	KeywordEntry::contextual(css::Keyword::Namespace.id()),
+	KeywordEntry::contextual(css::Keyword::Layer.id()),
+	KeywordEntry::contextual(css::Keyword::Media.id()),
+	KeywordEntry::contextual(css::Keyword::Supports.id()),
+	KeywordEntry::contextual(css::Keyword::Container.id()),
+	KeywordEntry::contextual(css::Keyword::Scope.id()),
+	KeywordEntry::contextual(css::Keyword::Page.id()),
+	KeywordEntry::contextual(css::Keyword::Keyframes.id()),
+	KeywordEntry::contextual(css::Keyword::Property.id()),
+	KeywordEntry::contextual(css::Keyword::Inherit.id()),
+	KeywordEntry::contextual(css::Keyword::Initial.id()),
+	KeywordEntry::contextual(css::Keyword::Unset.id()),
+	KeywordEntry::contextual(css::Keyword::Revert.id()),
+];
+
+#[cfg(feature = "delimiter")]
+/// Les délimiteurs, séparateurs et opérateurs de MikuScript : symboles,
+/// délimiteurs paires, puis opérateurs d'assignations, de comparaisons,
+/// arithmétiques, logiques et binaires.
+pub const MIKUSCRIPT_OPERATORS: &[&str] = &[
+	"\\", "@", "$", "#", ".", "..", "..=", ",", "::", ":", ";", "`", "?", "->",
+	"=>", "[", "]", "(", ")", "{", "}", "=", "+=", "-=", "*=", "/=", "%=",
+	"<<=", ">>=", "&=", "^=", "|=", "&&=", "||=", "==", "!=", ">", ">=", "<",
+	"<=", "+", "-", "/", "*", "%", "^^", "&&", "||", "!", "&", "|", "^", "~",
+	"<<", ">>",
+];
+
+#[cfg(feature = "delimiter")]
+/// Les délimiteurs de CSS. Chaque délimiteur ne contient qu'un seul point de
+/// code, à la manière des jetons `<delim-token>` de CSS.
+pub const CSS_OPERATORS: &[&str] = &[
+	"\\", "@", "$", "#", ".", ",", ":", ";", "?", "[", "]", "(", ")", "{", "}",
+	"=", ">", "<", "+", "-", "/", "*", "%", "!", "&", "|", "^", "~",
+];
+
+#[cfg(feature = "delimiter")]
+/// Les délimiteurs de MSCSS : ceux de CSS, ainsi que les opérateurs de
+/// comparaison.
+pub const MSCSS_OPERATORS: &[&str] = &[
+	"\\", "@", "$", "#", ".", ",", ":", ";", "?", "[", "]", "(", ")", "{", "}",
+	"=", ">", "<", "+", "-", "/", "*", "%", "!", "&", "|", "^", "~", "==",
+	"!=", ">=", "<=",
+];
+
+#[cfg(feature = "delimiter")]
+/// Les délimiteurs de HTML.
+pub const HTML_OPERATORS: &[&str] = &["<", ">", "/", "=", "!", "?", "&", ";"];
+
+#[cfg(feature = "literal")]
+/// Les suffixes de types des nombres de MikuScript.
+pub const MIKUSCRIPT_NUMERIC_SUFFIXES: &[NumericSuffix] = &[
+	NumericSuffix::U8,
+	NumericSuffix::U16,
+	NumericSuffix::U32,
+	NumericSuffix::U64,
+	NumericSuffix::U128,
+	NumericSuffix::Usize,
+	NumericSuffix::I8,
+	NumericSuffix::I16,
+	NumericSuffix::I32,
+	NumericSuffix::I64,
+	NumericSuffix::I128,
+	NumericSuffix::Isize,
+	NumericSuffix::F32,
+	NumericSuffix::F64,
+];
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Profil d'un langage : les choix de syntaxe que l'[analyseur
+/// lexical](crate::Tokenizer) respecte lors de l'analyse.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+pub struct LexerProfile {
+	/// Le nom du langage.
+	pub name: &'static str,
+
+	#[cfg(feature = "comment")]
+	/// Les débuts d'un commentaire d'une seule ligne.
+	///
+	/// Exemple: `//`, `--`.
+	pub line_comments: &'static [&'static str],
+
+	#[cfg(feature = "comment")]
+	/// Les débuts et fins d'un commentaire de plusieurs lignes.
+	///
+	/// Exemple: (`/*`, `*/`), (`<!--`, `-->`).
+	pub block_comments: &'static [(&'static str, &'static str)],
+
+	#[cfg(feature = "comment")]
+	/// Les commentaires de plusieurs lignes peuvent être imbriqués.
+	///
+	/// Exemple: `/* externe /* interne */ toujours commenté */`.
+	pub nested_block_comments: bool,
+
+	#[cfg(feature = "comment")]
+	/// Les commentaires de documentation sont reconnus : `///`, `//!`,
+	/// `/** */` et `/*! */`.
+	pub doc_comments: bool,
+
+	#[cfg(feature = "comment")]
+	/// Les commentaires `<!-- -->` suivent les états du tokenizer HTML
+	/// (WHATWG), leurs erreurs non fatales sont émises comme
+	/// [avertissements](crate::prelude::Severity::Warning).
+	pub html_comments: bool,
+
+	#[cfg(feature = "literal")]
+	/// Les points de code délimitant une chaîne de caractères.
+	pub string_quotes: &'static [char],
+
+	#[cfg(feature = "literal")]
+	/// Le point de code délimitant un caractère, par exemple `'C'`.
+	pub char_quote: Option<char>,
+
+	#[cfg(feature = "literal")]
+	/// Les séquences d'échappement des chaînes de caractères sont
+	/// interprétées : `\n`, `\t`, `\r`, `\\`, `\"`, `\'`, `` \` ``, `\$`,
+	/// `\0`, `\xHH`, `\u{...}` et la continuation de ligne.
+	pub string_escapes: bool,
+
+	#[cfg(feature = "literal")]
+	/// Les chaînes de caractères brutes sont reconnues : `r"..."`,
+	/// `r#"..."#`.
+	pub raw_strings: bool,
+
+	#[cfg(feature = "literal")]
+	/// Les chaînes de caractères de plusieurs lignes sont reconnues :
+	/// `"""..."""`.
+	pub triple_quoted_strings: bool,
+
+	#[cfg(feature = "literal")]
+	/// Les octets et chaînes d'octets sont reconnus : `b'x'`, `b"..."`.
+	/// L'octet utilise le [délimiteur de caractère](Self::char_quote).
+	pub byte_strings: bool,
+
+	#[cfg(feature = "literal")]
+	/// Les chaînes de caractères modèles sont reconnues : `` `a ${b} c` ``.
+	pub template_strings: bool,
+
+	#[cfg(feature = "literal")]
+	/// Les préfixes de nombres autorisés.
+	pub numeric_prefixes: &'static [NumericPrefix],
+
+	#[cfg(feature = "literal")]
+	/// Les chiffres d'un nombre peuvent être séparés par un U+005F LOW LINE
+	/// (_), placé entre deux chiffres : `1_000_000`.
+	pub numeric_separators: bool,
+
+	#[cfg(feature = "literal")]
+	/// Un nombre suivi d'un point final est un nombre à virgule flottante :
+	/// `1.`. Le point n'appartient pas au nombre lorsqu'il est suivi d'un
+	/// autre point (`1..2`) ou d'un identifiant (`1.max(2)`).
+	pub numeric_trailing_dot: bool,
+
+	#[cfg(feature = "literal")]
+	/// Un nombre décimal peut commencer par des zéros : `007`. Sinon, seul
+	/// le nombre `0` lui-même, ou suivi d'une virgule, d'un préfixe ou d'un
+	/// suffixe, peut commencer par un zéro.
+	pub numeric_leading_zeros: bool,
+
+	#[cfg(feature = "literal")]
+	/// Les nombres suivent CSS Syntax Level 3 : un signe `+` ou `-` peut les
+	/// précéder, et un nombre suivi d'un U+0025 PERCENTAGE SIGN (%) ou d'un
+	/// identifiant est un pourcentage ou une dimension : `50%`, `10px`.
+	pub numeric_dimensions: bool,
+
+	#[cfg(feature = "literal")]
+	/// Les suffixes de types autorisés après un nombre : `10u8`, `2.0f32`.
+	/// Lorsque la liste est vide, les lettres suivant un nombre ne lui
+	/// appartiennent pas.
+	pub numeric_suffixes: &'static [NumericSuffix],
+
+	#[cfg(feature = "literal")]
+	/// Les mots `true` et `false` sont des littéraux booléens. Sinon, ce
+	/// sont des identifiants comme les autres, comme en CSS : `true-color`.
+	pub booleans: bool,
+
+	#[cfg(feature = "keyword")]
+	/// Les mots-clés, réservés ou contextuels, à partir desquels l'analyseur
+	/// construit sa [table](crate::keyword::KeywordTable). Un mot-clé qui
+	/// n'est pas dans cette liste est analysé comme un identifiant.
+	pub keywords: &'static [KeywordEntry],
+
+	#[cfg(feature = "identifier")]
+	/// Les mots-clés et les identifiants sont insensibles à la casse ASCII,
+	/// comme les noms de propriétés CSS ou les noms de balises HTML : seules
+	/// les lettres ASCII sont converties en minuscules.
+	pub ascii_case_insensitive: bool,
+
+	#[cfg(feature = "identifier")]
+	/// Les identifiants suivent la syntaxe CSS : ils peuvent commencer par
+	/// `-` ou `--` (`-webkit-box`, `--custom`), contenir des `-` et des
+	/// séquences d'échappement (`\70 x`).
+	pub css_identifiers: bool,
+
+	#[cfg(feature = "delimiter")]
+	/// Les délimiteurs, séparateurs et opérateurs reconnus. Le plus long
+	/// délimiteur de cette liste est toujours choisi.
+	pub operators: &'static [&'static str],
+}
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+/// Les préfixes de nombres.
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+pub enum NumericPrefix {
+	/// `0x`
+	Hexadecimal,
+
+	/// `0b`
+	Binary,
+
+	/// `0o`
+	Octal,
+}
+
+/// Les suffixes de types des nombres.
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+pub enum NumericSuffix {
+	/// `u8`
+	U8,
+
+	/// `u16`
+	U16,
+
+	/// `u32`
+	U32,
+
+	/// `u64`
+	U64,
+
+	/// `u128`
+	U128,
+
+	/// `usize`
+	Usize,
+
+	/// `i8`
+	I8,
+
+	/// `i16`
+	I16,
+
+	/// `i32`
+	I32,
+
+	/// `i64`
+	I64,
+
+	/// `i128`
+	I128,
+
+	/// `isize`
+	Isize,
+
+	/// `f32`
+	F32,
+
+	/// `f64`
+	F64,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl LexerProfile {
+	/// Profil de MikuScript, syntaxe inspirée de Rust, Go, JavaScript.
+	///
+	/// Le point de code U+0027 APOSTROPHE (') est réservé aux caractères, il
+	/// ne délimite pas une chaîne de caractères.
+	pub const fn mikuscript() -> Self {
+		Self {
+			name: "ms",
+			#[cfg(feature = "comment")]
+			line_comments: &["//", "--"],
+			#[cfg(feature = "comment")]
+			block_comments: &[("/*", "*/")],
+			#[cfg(feature = "comment")]
+			nested_block_comments: true,
+			#[cfg(feature = "comment")]
+			doc_comments: true,
+			#[cfg(feature = "comment")]
+			html_comments: false,
+			#[cfg(feature = "literal")]
+			string_quotes: &['"'],
+			#[cfg(feature = "literal")]
+			char_quote: Some('\''),
+			#[cfg(feature = "literal")]
+			string_escapes: true,
+			#[cfg(feature = "literal")]
+			raw_strings: true,
+			#[cfg(feature = "literal")]
+			triple_quoted_strings: true,
+			#[cfg(feature = "literal")]
+			byte_strings: true,
+			#[cfg(feature = "literal")]
+			template_strings: true,
+			#[cfg(feature = "literal")]
+			numeric_prefixes: &[
+				NumericPrefix::Hexadecimal,
+				NumericPrefix::Binary,
+				NumericPrefix::Octal,
+			],
+			#[cfg(feature = "literal")]
+			numeric_separators: true,
+			#[cfg(feature = "literal")]
+			numeric_trailing_dot: true,
+			#[cfg(feature = "literal")]
+			numeric_leading_zeros: false,
+			#[cfg(feature = "literal")]
+			numeric_dimensions: false,
+			#[cfg(feature = "literal")]
+			numeric_suffixes: MIKUSCRIPT_NUMERIC_SUFFIXES,
+			#[cfg(feature = "literal")]
+			booleans: true,
+			#[cfg(feature = "keyword")]
+			keywords: MIKUSCRIPT_KEYWORDS,
+			#[cfg(feature = "identifier")]
+			ascii_case_insensitive: false,
+			#[cfg(feature = "identifier")]
+			css_identifiers: false,
+			#[cfg(feature = "delimiter")]
+			operators: MIKUSCRIPT_OPERATORS,
+		}
+	}
+
+	/// Profil de MSCSS, syntaxe inspirée de (S)CSS.
+	pub const fn mscss() -> Self {
+		Self {
+			name: "mscss",
+			#[cfg(feature = "comment")]
+			line_comments: &["//"],
+			#[cfg(feature = "comment")]
+			block_comments: &[("/*", "*/")],
+			#[cfg(feature = "comment")]
+			nested_block_comments: false,
+			#[cfg(feature = "comment")]
+			doc_comments: true,
+			#[cfg(feature = "comment")]
+			html_comments: false,
+			#[cfg(feature = "literal")]
+			string_quotes: &['"', '\''],
+			#[cfg(feature = "literal")]
+			char_quote: None,
+			#[cfg(feature = "literal")]
+			string_escapes: true,
+			#[cfg(feature = "literal")]
+			raw_strings: false,
+			#[cfg(feature = "literal")]
+			triple_quoted_strings: false,
+			#[cfg(feature = "literal")]
+			byte_strings: false,
+			#[cfg(feature = "literal")]
+			template_strings: false,
+			#[cfg(feature = "literal")]
+			numeric_prefixes: &[],
+			#[cfg(feature = "literal")]
+			numeric_separators: false,
+			#[cfg(feature = "literal")]
+			numeric_trailing_dot: false,
+			#[cfg(feature = "literal")]
+			numeric_leading_zeros: true,
+			#[cfg(feature = "literal")]
+			numeric_dimensions: true,
+			#[cfg(feature = "literal")]
+			numeric_suffixes: &[],
+			#[cfg(feature = "literal")]
+			booleans: false,
+			#[cfg(feature = "keyword")]
+			keywords: MSCSS_KEYWORDS,
+			#[cfg(feature = "identifier")]
+			ascii_case_insensitive: false,
+			#[cfg(feature = "identifier")]
+			css_identifiers: true,
+			#[cfg(feature = "delimiter")]
+			operators: MSCSS_OPERATORS,
+		}
+	}
+
+	/// Profil de MSX, syntaxe inspirée de HTML, Vue, JSX.
+	pub const fn msx() -> Self {
+		Self {
+			name: "msx",
+			#[cfg(feature = "comment")]
+			line_comments: &["//"],
+			#[cfg(feature = "comment")]
+			block_comments: &[("/*", "*/"), ("<!--", "-->")],
+			#[cfg(feature = "comment")]
+			nested_block_comments: true,
+			#[cfg(feature = "comment")]
+			doc_comments: true,
+			#[cfg(feature = "comment")]
+			html_comments: true,
+			#[cfg(feature = "literal")]
+			string_quotes: &['"', '\''],
+			#[cfg(feature = "literal")]
+			char_quote: None,
+			#[cfg(feature = "literal")]
+			string_escapes: true,
+			#[cfg(feature = "literal")]
+			raw_strings: true,
+			#[cfg(feature = "literal")]
+			triple_quoted_strings: true,
+			#[cfg(feature = "literal")]
+			byte_strings: true,
+			#[cfg(feature = "literal")]
+			template_strings: true,
+			#[cfg(feature = "literal")]
+			numeric_prefixes: &[
+				NumericPrefix::Hexadecimal,
+				NumericPrefix::Binary,
+				NumericPrefix::Octal,
+			],
+			#[cfg(feature = "literal")]
+			numeric_separators: true,
+			#[cfg(feature = "literal")]
+			numeric_trailing_dot: true,
+			#[cfg(feature = "literal")]
+			numeric_leading_zeros: false,
+			#[cfg(feature = "literal")]
+			numeric_dimensions: false,
+			#[cfg(feature = "literal")]
+			numeric_suffixes: MIKUSCRIPT_NUMERIC_SUFFIXES,
+			#[cfg(feature = "literal")]
+			booleans: true,
+			#[cfg(feature = "keyword")]
+			keywords: MIKUSCRIPT_KEYWORDS,
+			#[cfg(feature = "identifier")]
+			ascii_case_insensitive: false,
+			#[cfg(feature = "identifier")]
+			css_identifiers: false,
+			#[cfg(feature = "delimiter")]
+			operators: MIKUSCRIPT_OPERATORS,
+		}
+	}
+
+	/// Profil de CSS.
+	pub const fn css() -> Self {
+		Self {
+			name: "css",
+			#[cfg(feature = "comment")]
+			line_comments: &[],
+			#[cfg(feature = "comment")]
+			block_comments: &[("/*", "*/")],
+			#[cfg(feature = "comment")]
+			nested_block_comments: false,
+			#[cfg(feature = "comment")]
+			doc_comments: false,
+			#[cfg(feature = "comment")]
+			html_comments: false,
+			#[cfg(feature = "literal")]
+			string_quotes: &['"', '\''],
+			#[cfg(feature = "literal")]
+			char_quote: None,
+			#[cfg(feature = "literal")]
+			string_escapes: false,
+			#[cfg(feature = "literal")]
+			raw_strings: false,
+			#[cfg(feature = "literal")]
+			triple_quoted_strings: false,
+			#[cfg(feature = "literal")]
+			byte_strings: false,
+			#[cfg(feature = "literal")]
+			template_strings: false,
+			#[cfg(feature = "literal")]
+			numeric_prefixes: &[],
+			#[cfg(feature = "literal")]
+			numeric_separators: false,
+			#[cfg(feature = "literal")]
+			numeric_trailing_dot: false,
+			#[cfg(feature = "literal")]
+			numeric_leading_zeros: true,
+			#[cfg(feature = "literal")]
+			numeric_dimensions: true,
+			#[cfg(feature = "literal")]
+			numeric_suffixes: &[],
+			#[cfg(feature = "literal")]
+			booleans: false,
+			#[cfg(feature = "keyword")]
+			keywords: CSS_KEYWORDS,
+			#[cfg(feature = "identifier")]
+			ascii_case_insensitive: true,
+			#[cfg(feature = "identifier")]
+			css_identifiers: true,
+			#[cfg(feature = "delimiter")]
+			operators: CSS_OPERATORS,
+		}
+	}
+
+	/// Profil de HTML.
+	pub const fn html() -> Self {
+		Self {
+			name: "html",
+			#[cfg(feature = "comment")]
+			line_comments: &[],
+			#[cfg(feature = "comment")]
+			block_comments: &[("<!--", "-->")],
+			#[cfg(feature = "comment")]
+			nested_block_comments: false,
+			#[cfg(feature = "comment")]
+			doc_comments: false,
+			#[cfg(feature = "comment")]
+			html_comments: true,
+			#[cfg(feature = "literal")]
+			string_quotes: &['"', '\''],
+			#[cfg(feature = "literal")]
+			char_quote: None,
+			#[cfg(feature = "literal")]
+			string_escapes: false,
+			#[cfg(feature = "literal")]
+			raw_strings: false,
+			#[cfg(feature = "literal")]
+			triple_quoted_strings: false,
+			#[cfg(feature = "literal")]
+			byte_strings: false,
+			#[cfg(feature = "literal")]
+			template_strings: false,
+			#[cfg(feature = "literal")]
+			numeric_prefixes: &[],
+			#[cfg(feature = "literal")]
+			numeric_separators: false,
+			#[cfg(feature = "literal")]
+			numeric_trailing_dot: false,
+			#[cfg(feature = "literal")]
+			numeric_leading_zeros: true,
+			#[cfg(feature = "literal")]
+			numeric_dimensions: false,
+			#[cfg(feature = "literal")]
+			numeric_suffixes: &[],
+			#[cfg(feature = "literal")]
+			booleans: false,
+			#[cfg(feature = "keyword")]
+			keywords: &[],
+			#[cfg(feature = "identifier")]
+			ascii_case_insensitive: true,
+			#[cfg(feature = "identifier")]
+			css_identifiers: false,
+			#[cfg(feature = "delimiter")]
+			operators: HTML_OPERATORS,
+		}
+	}
+}
+
+impl NumericPrefix {
+	/// Le point de code suivant le `0` du préfixe.
+	pub fn prefix(self) -> char {
+		match self {
+			| Self::Hexadecimal => 'x',
+			| Self::Binary => 'b',
+			| Self::Octal => 'o',
+		}
+	}
+
+	/// La base des chiffres suivant le préfixe.
+	pub fn radix(self) -> u32 {
+		match self {
+			| Self::Hexadecimal => 16,
+			| Self::Binary => 2,
+			| Self::Octal => 8,
+		}
+	}
+}
+
+impl NumericSuffix {
+	/// Le suffixe, tel qu'il est écrit après le nombre.
+	pub fn as_str(self) -> &'static str {
+		match self {
+			| Self::U8 => "u8",
+			| Self::U16 => "u16",
+			| Self::U32 => "u32",
+			| Self::U64 => "u64",
+			| Self::U128 => "u128",
+			| Self::Usize => "usize",
+			| Self::I8 => "i8",
+			| Self::I16 => "i16",
+			| Self::I32 => "i32",
+			| Self::I64 => "i64",
+			| Self::I128 => "i128",
+			| Self::Isize => "isize",
+			| Self::F32 => "f32",
+			| Self::F64 => "f64",
+		}
+	}
+
+	/// Le suffixe désigne un type de nombre à virgule flottante.
+	pub fn is_float(self) -> bool {
+		matches!(self, Self::F32 | Self::F64)
+	}
+
+	/// La plus grande valeur entière qu'un nombre de ce type peut écrire,
+	/// `None` pour un type à virgule flottante.
+	///
+	/// Pour un type signé, il s'agit de la valeur absolue de son minimum :
+	/// le signe est un jeton distinct, `-128i8` doit donc rester valide.
+	/// `usize` et `isize` sont considérés sur 64 bits.
+	pub fn max_integer(self) -> Option<u128> {
+		Some(match self {
+			| Self::U8 => u8::MAX.into(),
+			| Self::U16 => u16::MAX.into(),
+			| Self::U32 => u32::MAX.into(),
+			| Self::U64 | Self::Usize => u64::MAX.into(),
+			| Self::U128 => u128::MAX,
+			| Self::I8 => i8::MIN.unsigned_abs().into(),
+			| Self::I16 => i16::MIN.unsigned_abs().into(),
+			| Self::I32 => i32::MIN.unsigned_abs().into(),
+			| Self::I64 | Self::Isize => i64::MIN.unsigned_abs().into(),
+			| Self::I128 => i128::MIN.unsigned_abs(),
+			| Self::F32 | Self::F64 => return None,
+		})
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl Default for LexerProfile {
+	fn default() -> Self {
+		Self::mikuscript()
+	}
+}
+
+impl str::FromStr for LexerProfile {
+	type Err = &'static str;
+
+	fn from_str(name: &str) -> Result<Self, Self::Err> {
+		Ok(match name.to_ascii_lowercase().as_ref() {
+			| "ms" | "mikuscript" => Self::mikuscript(),
+			| "mscss" => Self::mscss(),
+			| "msx" => Self::msx(),
+			| "css" => Self::css(),
+			| "html" => Self::html(),
+			| _ => {
+				return Err(
+					r#"Un profil "ms", "mscss", "msx", "css" ou "html" est attendu"#,
+				);
+			}
+		})
+	}
+}
+
+// ---- //
+// Test //
+// ---- //
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_from_str() {
+		assert_eq!("ms".parse(), Ok(LexerProfile::mikuscript()));
+		assert_eq!("MSCSS".parse(), Ok(LexerProfile::mscss()));
+		assert_eq!("html".parse::<LexerProfile>().map(|p| p.name), Ok("html"));
+		assert!("rust".parse::<LexerProfile>().is_err());
+	}
+
+	#[cfg(feature = "delimiter")]
+	#[test]
+	fn test_operators_are_delimiters() {
+		use codepoints::CodePoint;
+
+		use crate::delimiter::Delimiter;
+
+		let profiles = [
+			LexerProfile::mikuscript(),
+			LexerProfile::mscss(),
+			LexerProfile::msx(),
+			LexerProfile::css(),
+			LexerProfile::html(),
+		];
+
+		for operator in profiles.iter().flat_map(|p| p.operators) {
+			let codepoints: Vec<_> =
+				operator.chars().map(CodePoint::from).collect();
+			let delimiter = match codepoints.as_slice() {
+				| [a] => Delimiter::try_from(*a),
+				| [a, b] => Delimiter::try_from([*a, *b]),
+				| [a, b, c] => Delimiter::try_from([*a, *b, *c]),
+				| _ => panic!("opérateur trop long : {operator}"),
+			};
+			assert!(delimiter.is_ok(), "{operator}");
+		}
+	}
+}
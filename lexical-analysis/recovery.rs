@@ -0,0 +1,475 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+use codepoints::{CodePoint, CodePointInterface};
+use location::{Location, Span};
+
+use crate::error::LexicalError;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Un diagnostic émis lors d'une analyse lexicale.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+pub struct Diagnostic {
+	/// L'erreur rencontrée.
+	pub error: LexicalError,
+
+	/// La gravité du diagnostic.
+	pub severity: Severity,
+
+	/// La portion du code source concernée par l'erreur.
+	pub span: Span,
+}
+
+/// Jeton d'erreur, produit à la place d'un jeton lorsqu'un algorithme
+/// d'analyse échoue.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+pub struct ErrorToken {
+	/// Le texte fautif, depuis le début du jeton jusqu'au point de
+	/// resynchronisation.
+	pub text: String,
+
+	/// L'erreur rencontrée.
+	pub error: LexicalError,
+
+	/// La portion du code source occupée par le texte fautif.
+	pub span: Span,
+}
+
+/// Le résultat d'une analyse lexicale qui ne s'arrête pas à la première
+/// erreur.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+pub struct RecoveredTokens<Token> {
+	/// La liste complète des jetons, y compris les jetons d'erreur.
+	pub tokens: Vec<Result<Token, ErrorToken>>,
+
+	/// L'ensemble des diagnostics émis pendant l'analyse.
+	pub diagnostics: Vec<Diagnostic>,
+}
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+/// La gravité d'un [diagnostic](Diagnostic).
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+pub enum Severity {
+	/// L'analyse du jeton a échoué, un [jeton d'erreur](ErrorToken) est
+	/// produit à sa place.
+	Error,
+
+	/// Erreur non fatale : le jeton est tout de même produit.
+	Warning,
+}
+
+/// Stratégie de resynchronisation après une erreur.
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+pub enum Resynchronization {
+	/// Avance jusqu'à la fin de la ligne. Le saut de ligne n'est pas
+	/// consommé.
+	EndOfLine,
+
+	/// Avance jusqu'au prochain point de code pouvant commencer un jeton.
+	NextStartCodePoint,
+
+	/// Avance jusqu'à la fin du nombre : les lettres, les chiffres, les
+	/// U+005F LOW LINE (_), et les U+002E FULL STOP (.) suivis d'un chiffre.
+	EndOfNumber,
+
+	/// Avance jusqu'au guillemet fermant, inclus, lorsqu'il se trouve sur
+	/// la même ligne ; jusqu'à la fin de la ligne sinon. Le guillemet est le
+	/// premier U+0027 APOSTROPHE (') ou U+0022 QUOTATION MARK (") du texte
+	/// déjà consommé par le jeton fautif.
+	ClosingQuote,
+}
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait TokenizerRecovery {
+	type Token;
+	type Unit: CodePointInterface;
+
+	/// L'emplacement du dernier point de code consommé.
+	fn location(&self) -> Location;
+
+	/// Consomme le prochain jeton. Retourne [None] à la fin du flux.
+	fn consume_token(&mut self) -> Option<Result<Self::Token, LexicalError>>;
+
+	/// Retourne, et oublie, le texte consommé depuis le début du jeton
+	/// actuel.
+	fn take_consumed_text(&mut self) -> String;
+
+	/// Le prochain point de code. NE DOIT PAS le consommer.
+	fn peek_codepoint(&mut self) -> CodePoint<Self::Unit>;
+
+	/// Les `n` prochains points de code. NE DOIT PAS les consommer. Les
+	/// points de code manquants, en fin de flux, valent [CodePoint::EOF].
+	fn peek_n_codepoints(&mut self, n: usize) -> Vec<CodePoint<Self::Unit>>;
+
+	/// Consomme le prochain point de code.
+	fn consume_codepoint(&mut self) -> CodePoint<Self::Unit>;
+
+	/// Retourne, et oublie, les diagnostics non fatals (voir
+	/// [Severity::Warning]) émis depuis le dernier appel.
+	fn take_diagnostics(&mut self) -> Vec<Diagnostic> {
+		Vec::new()
+	}
+
+	/// Vérifie qu'un point de code peut commencer un jeton.
+	fn is_token_start(&self, codepoint: &CodePoint<Self::Unit>) -> bool {
+		match codepoint {
+			| CodePoint::Unit(unit) => {
+				unit.is_ident_start()
+					|| unit.is_digit()
+					|| matches!(unit.as_char(), '@' | '$' | '`')
+			}
+			| CodePoint::NULL | CodePoint::Ignore => false,
+			| _ => true,
+		}
+	}
+
+	/// Avance dans le flux jusqu'au point de resynchronisation, et retourne
+	/// le texte ignoré.
+	///
+	/// Lorsque aucun point de code n'a été consommé par le jeton fautif, le
+	/// point de code suivant est consommé quoi qu'il arrive, afin de
+	/// toujours progresser dans le flux.
+	fn resynchronize(&mut self, strategy: Resynchronization) -> String {
+		let mut text = self.take_consumed_text();
+
+		if text.is_empty() {
+			match self.peek_codepoint() {
+				| CodePoint::EOF => return text,
+				| _ => {
+					let codepoint = self.consume_codepoint();
+					text.extend(codepoint.maybe_unit());
+				}
+			}
+		}
+
+		let quote = text.chars().find(|ch| matches!(ch, '\'' | '"'));
+
+		loop {
+			let next = self.peek_codepoint();
+			let stop = match strategy {
+				| Resynchronization::EndOfLine
+				| Resynchronization::ClosingQuote => {
+					matches!(next, CodePoint::EOF | CodePoint::Newline(_))
+				}
+				| Resynchronization::NextStartCodePoint => {
+					self.is_token_start(&next)
+				}
+				| Resynchronization::EndOfNumber => {
+					match next.maybe_unit().map(|unit| unit.as_char()) {
+						| Some('.') => !self.peek_n_codepoints(2)[1].is_digit(),
+						| Some(ch) => !ch.is_alphanumeric() && ch != '_',
+						| None => true,
+					}
+				}
+			};
+
+			if stop {
+				break;
+			}
+
+			let codepoint = self.consume_codepoint();
+			text.extend(codepoint.maybe_unit());
+
+			if strategy == Resynchronization::ClosingQuote {
+				match codepoint.maybe_unit() {
+					| Some('\\') => {
+						if !matches!(
+							self.peek_codepoint(),
+							CodePoint::EOF | CodePoint::Newline(_)
+						) {
+							let escaped = self.consume_codepoint();
+							text.extend(escaped.maybe_unit());
+						}
+					}
+					| unit if unit.is_some() && unit == quote => break,
+					| _ => {}
+				}
+			}
+		}
+
+		text
+	}
+
+	/// Consomme tous les jetons d'un flux. Chaque échec est transformé en
+	/// [jeton d'erreur](ErrorToken), suivi d'une resynchronisation
+	/// (voir [LexicalError::resynchronization]), puis l'analyse continue.
+	fn consume_tokens_with_recovery(&mut self) -> RecoveredTokens<Self::Token> {
+		let mut tokens = vec![];
+		let mut diagnostics = vec![];
+
+		loop {
+			let mut start = self.location();
+			start.column = start.column.saturating_add(1);
+
+			let token = self.consume_token();
+			diagnostics.extend(self.take_diagnostics());
+
+			let error = match token {
+				| None => break,
+				| Some(Ok(token)) => {
+					self.take_consumed_text();
+					tokens.push(Ok(token));
+					continue;
+				}
+				| Some(Err(error)) => error,
+			};
+
+			let text = self.resynchronize(error.resynchronization());
+			let span = Span::new(start, self.location());
+
+			diagnostics.push(Diagnostic {
+				error: error.clone(),
+				severity: Severity::Error,
+				span,
+			});
+			tokens.push(Err(ErrorToken { text, error, span }));
+		}
+
+		RecoveredTokens {
+			tokens,
+			diagnostics,
+		}
+	}
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl LexicalError {
+	/// La stratégie de resynchronisation à appliquer après cette erreur.
+	///
+	/// Une chaîne de caractères mal formée ou non terminée est ignorée
+	/// jusqu'à la fin de la ligne. Un caractère ou un octet dont la
+	/// séquence d'échappement est invalide est ignoré jusqu'à son guillemet
+	/// fermant ; lorsque le guillemet fermant a déjà été consommé (`''`,
+	/// `'ab'`), l'analyse reprend au jeton suivant. Un nombre invalide est
+	/// ignoré jusqu'à sa fin : `08`, `1.2.3`, `0b1012`. Les autres erreurs
+	/// sont ignorées jusqu'au prochain point de code pouvant commencer un
+	/// jeton.
+	pub fn resynchronization(&self) -> Resynchronization {
+		#[cfg(feature = "literal")]
+		use crate::literal::{
+			ByteParseError, CharParseError, LiteralParseError,
+		};
+
+		match self {
+			#[cfg(feature = "literal")]
+			| Self::Literal(LiteralParseError::String(_)) => {
+				Resynchronization::EndOfLine
+			}
+			#[cfg(feature = "literal")]
+			| Self::Literal(LiteralParseError::Char(error)) => match error {
+				| CharParseError::Empty { .. }
+				| CharParseError::TooManyCharacters { .. } => {
+					Resynchronization::NextStartCodePoint
+				}
+				| CharParseError::Escape(_) => Resynchronization::ClosingQuote,
+				| CharParseError::IsNot { .. }
+				| CharParseError::Unterminated { .. } => Resynchronization::EndOfLine,
+			},
+			#[cfg(feature = "literal")]
+			| Self::Literal(LiteralParseError::Byte(error)) => match error {
+				| ByteParseError::Empty { .. }
+				| ByteParseError::TooManyCharacters { .. } => {
+					Resynchronization::NextStartCodePoint
+				}
+				| ByteParseError::Escape(_)
+				| ByteParseError::NonAscii { .. } => Resynchronization::ClosingQuote,
+				| ByteParseError::IsNot { .. }
+				| ByteParseError::Unterminated { .. } => Resynchronization::EndOfLine,
+			},
+			#[cfg(feature = "literal")]
+			| Self::Literal(
+				LiteralParseError::Integer(_) | LiteralParseError::Float(_),
+			) => Resynchronization::EndOfNumber,
+			| _ => Resynchronization::NextStartCodePoint,
+		}
+	}
+}
+
+// ---- //
+// Test //
+// ---- //
+
+#[cfg(test)]
+mod tests {
+	use location::LocationInterface;
+	use stream::prelude::{InputStream, StreamIterator};
+
+	use super::*;
+	use crate::{
+		delimiter::DelimiterParseError,
+		literal::{FloatParseError, StringParseError},
+	};
+
+	/// Analyseur minimal : des mots (lettres), des espaces et des chaînes de
+	/// caractères.
+	struct Words<I: Iterator<Item = char>> {
+		stream: InputStream<I, char>,
+		location: Location,
+		consumed: String,
+	}
+
+	impl<I: Iterator<Item = char>> Words<I> {
+		fn new(source: I) -> Self {
+			Self {
+				stream: InputStream::new(source),
+				location: Location::new(),
+				consumed: String::new(),
+			}
+		}
+	}
+
+	impl<I: Iterator<Item = char>> TokenizerRecovery for Words<I> {
+		type Token = String;
+		type Unit = char;
+
+		fn location(&self) -> Location {
+			self.location
+		}
+
+		fn consume_token(&mut self) -> Option<Result<String, LexicalError>> {
+			let start = self.location;
+			match self.consume_codepoint() {
+				| CodePoint::EOF => None,
+				| CodePoint::Whitespace(_) | CodePoint::Newline(_) => {
+					Some(Ok(self.consumed.clone()))
+				}
+				| CodePoint::QUOTATION_MARK => loop {
+					match self.peek_codepoint() {
+						| CodePoint::EOF | CodePoint::Newline(_) => {
+							return Some(Err(StringParseError::Unterminated {
+								line: start.line,
+								column: start.column + 1,
+							}
+							.into()));
+						}
+						| CodePoint::QUOTATION_MARK => {
+							self.consume_codepoint();
+							return Some(Ok(self.consumed.clone()));
+						}
+						| _ => {
+							self.consume_codepoint();
+						}
+					}
+				},
+				| codepoint if codepoint.is_ident_start() => {
+					while self.peek_codepoint().is_ident_start() {
+						self.consume_codepoint();
+					}
+					Some(Ok(self.consumed.clone()))
+				}
+				| codepoint => Some(Err(DelimiterParseError::Invalid {
+					found: codepoint.unit().to_string(),
+				}
+				.into())),
+			}
+		}
+
+		fn take_consumed_text(&mut self) -> String {
+			core::mem::take(&mut self.consumed)
+		}
+
+		fn peek_codepoint(&mut self) -> CodePoint<char> {
+			self.stream.peek_next().unwrap_or(CodePoint::EOF)
+		}
+
+		fn peek_n_codepoints(&mut self, n: usize) -> Vec<CodePoint<char>> {
+			self.stream
+				.peek_n_next(n)
+				.into_iter()
+				.map(|codepoint| codepoint.unwrap_or(CodePoint::EOF))
+				.collect()
+		}
+
+		fn consume_codepoint(&mut self) -> CodePoint<char> {
+			let codepoint =
+				self.stream.consume_next().unwrap_or(CodePoint::EOF);
+			match codepoint {
+				| CodePoint::EOF => {}
+				| CodePoint::Newline(_) => {
+					self.location.increment_line();
+					self.location.column = 0;
+				}
+				| _ => self.location.increment_column(),
+			}
+			self.consumed.extend(codepoint.maybe_unit());
+			codepoint
+		}
+	}
+
+	#[test]
+	fn test_recovery_reports_every_error() {
+		let source = "hello \"unterminated string\nworld \u{1}\u{1} again\n";
+		let recovered =
+			Words::new(source.chars()).consume_tokens_with_recovery();
+
+		assert_eq!(recovered.tokens.len(), 10);
+		assert_eq!(recovered.tokens.iter().filter(|t| t.is_ok()).count(), 8);
+		assert_eq!(recovered.diagnostics.len(), 2);
+
+		let Err(string) = &recovered.tokens[2] else {
+			panic!("un jeton d'erreur est attendu");
+		};
+		assert_eq!(string.text, "\"unterminated string");
+		assert_eq!(string.span.start.line, 1);
+		assert_eq!(string.span.start.column, 7);
+		assert_eq!(string.span.end.column, 26);
+		assert_eq!(recovered.diagnostics[0].span, string.span);
+
+		let Err(invalid) = &recovered.tokens[6] else {
+			panic!("un jeton d'erreur est attendu");
+		};
+		assert_eq!(invalid.text, "\u{1}\u{1}");
+		assert_eq!(invalid.span.start.line, 2);
+		assert_eq!(invalid.span.start.column, 7);
+		assert_eq!(invalid.span.end.column, 8);
+		assert_eq!(recovered.tokens[8], Ok("again".to_owned()));
+	}
+
+	#[test]
+	fn test_resynchronization_strategy() {
+		assert_eq!(
+			LexicalError::from(StringParseError::UnescapedNewline {
+				span: Span::default()
+			})
+			.resynchronization(),
+			Resynchronization::EndOfLine
+		);
+		assert_eq!(
+			LexicalError::from(DelimiterParseError::Invalid {
+				found: "#".to_owned()
+			})
+			.resynchronization(),
+			Resynchronization::NextStartCodePoint
+		);
+		assert_eq!(
+			LexicalError::from(FloatParseError::Overflow {
+				span: Span::default()
+			})
+			.resynchronization(),
+			Resynchronization::EndOfNumber
+		);
+	}
+}
@@ -0,0 +1,171 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+use core::fmt;
+
+use location::Span;
+use stream::prelude::StreamIteratorItem;
+
+#[cfg(feature = "comment")]
+use crate::comment::CommentOutput;
+#[cfg(feature = "delimiter")]
+use crate::delimiter::DelimiterOutput;
+#[cfg(feature = "identifier")]
+use crate::identifier::IdentifierOutput;
+#[cfg(feature = "keyword")]
+use crate::keyword::KeywordOutput;
+#[cfg(feature = "literal")]
+use crate::literal::Literal;
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+/// Les jetons produits par l'analyse lexicale.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq)]
+pub enum Token {
+	#[cfg(feature = "comment")]
+	/// Commentaire.
+	Comment(CommentOutput),
+
+	#[cfg(feature = "delimiter")]
+	/// Délimiteur, séparateur ou opérateur.
+	Delimiter(DelimiterOutput),
+
+	#[cfg(feature = "keyword")]
+	/// Mot-clé.
+	Keyword(KeywordOutput),
+
+	#[cfg(feature = "identifier")]
+	/// Identifiant.
+	Identifier(IdentifierOutput),
+
+	#[cfg(feature = "literal")]
+	/// Littéral : nombre, chaîne de caractères, booléen.
+	Literal(Literal),
+
+	/// Suite d'espaces blancs, sauts de ligne exclus.
+	Whitespace(Span),
+
+	/// Saut de ligne.
+	Newline(Span),
+
+	/// Fin du flux.
+	EOF(Span),
+}
+
+/// Le type d'un [jeton](Token).
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+pub enum TokenKind {
+	#[cfg(feature = "comment")]
+	Comment,
+	#[cfg(feature = "delimiter")]
+	Delimiter,
+	#[cfg(feature = "keyword")]
+	Keyword,
+	#[cfg(feature = "identifier")]
+	Identifier,
+	#[cfg(feature = "literal")]
+	Literal,
+	Whitespace,
+	Newline,
+	EOF,
+
+	/// Ignore.
+	// NOTE(phisyx): aucun jeton produit par l'analyseur n'a ce type, il
+	// existe pour satisfaire l'interface des flux (voir
+	// StreamIteratorItem::ignore).
+	Ignore,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl Token {
+	/// La portion du code source occupée par le jeton.
+	pub fn span(&self) -> Span {
+		match self {
+			#[cfg(feature = "comment")]
+			| Self::Comment(comment) => comment.span,
+			#[cfg(feature = "delimiter")]
+			| Self::Delimiter(delimiter) => delimiter.span,
+			#[cfg(feature = "keyword")]
+			| Self::Keyword(keyword) => keyword.span,
+			#[cfg(feature = "identifier")]
+			| Self::Identifier(identifier) => identifier.span,
+			#[cfg(feature = "literal")]
+			| Self::Literal(literal) => literal.span(),
+			| Self::Whitespace(span)
+			| Self::Newline(span)
+			| Self::EOF(span) => *span,
+		}
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl StreamIteratorItem for Token {
+	type Kind = TokenKind;
+
+	fn eof() -> Self::Kind {
+		TokenKind::EOF
+	}
+
+	fn ignore() -> Self::Kind {
+		TokenKind::Ignore
+	}
+
+	fn kind(&self) -> &Self::Kind {
+		match self {
+			#[cfg(feature = "comment")]
+			| Self::Comment(_) => &TokenKind::Comment,
+			#[cfg(feature = "delimiter")]
+			| Self::Delimiter(_) => &TokenKind::Delimiter,
+			#[cfg(feature = "keyword")]
+			| Self::Keyword(_) => &TokenKind::Keyword,
+			#[cfg(feature = "identifier")]
+			| Self::Identifier(_) => &TokenKind::Identifier,
+			#[cfg(feature = "literal")]
+			| Self::Literal(_) => &TokenKind::Literal,
+			| Self::Whitespace(_) => &TokenKind::Whitespace,
+			| Self::Newline(_) => &TokenKind::Newline,
+			| Self::EOF(_) => &TokenKind::EOF,
+		}
+	}
+}
+
+impl fmt::Display for Token {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			#[cfg(feature = "comment")]
+			| Self::Comment(comment) => {
+				write!(
+					f,
+					"comment-{}",
+					comment.ty.to_string().to_ascii_lowercase()
+				)
+			}
+			#[cfg(feature = "delimiter")]
+			| Self::Delimiter(delimiter) => write!(f, "{}", delimiter.delimiter),
+			#[cfg(feature = "keyword")]
+			| Self::Keyword(keyword) => write!(f, "keyword-{}", keyword.keyword),
+			#[cfg(feature = "identifier")]
+			| Self::Identifier(identifier) => {
+				write!(f, "identifier-{}", identifier.identifier)
+			}
+			#[cfg(feature = "literal")]
+			| Self::Literal(literal) => write!(f, "{literal}"),
+			| Self::Whitespace(_) => write!(f, "whitespace"),
+			| Self::Newline(_) => write!(f, "newline"),
+			| Self::EOF(_) => write!(f, "EOF"),
+		}
+	}
+}
@@ -0,0 +1,3980 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+use std::collections::VecDeque;
+
+use codepoints::{CodePoint, CodePointInterface};
+use location::{Location, LocationInterface, Span};
+use stream::prelude::StreamIterator;
+#[cfg(feature = "identifier")]
+use unicode_normalization::UnicodeNormalization;
+
+#[cfg(feature = "comment")]
+use crate::comment::{
+	CommentOutput, CommentParseError, CommentState, CommentType,
+};
+#[cfg(feature = "delimiter")]
+use crate::delimiter::{Delimiter, DelimiterOutput, DelimiterParseError};
+#[cfg(feature = "identifier")]
+use crate::identifier::{
+	ConfusableDetector, IdentifierOutput, IdentifierParseError,
+};
+#[cfg(feature = "keyword")]
+use crate::keyword::{
+	KeywordKind, KeywordMatcher, KeywordOutput, KeywordTable,
+};
+#[cfg(feature = "literal")]
+use crate::literal::{
+	Bool, BoolOutput, ByteOutput, ByteParseError, ByteStringOutput, CharOutput,
+	CharParseError, DimensionOutput, FloatOutput, FloatParseError, Integer,
+	IntegerOutput, IntegerParseError, IntegerState, Literal, LiteralParseError,
+	NumberOutput, NumberType, NumericSign, PercentageOutput, StringOutput,
+	StringParseError, StringState, TemplateOutput, TemplatePart,
+};
+#[cfg(feature = "literal")]
+use crate::profile::{NumericPrefix, NumericSuffix};
+#[cfg(any(feature = "comment", feature = "literal"))]
+use crate::ParseState;
+use crate::{
+	algorithms::TokenizerAlgorithms,
+	error::LexicalError,
+	profile::LexerProfile,
+	recovery::{Diagnostic, Severity, TokenizerRecovery},
+	token::Token,
+};
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Analyseur lexical générique, implémentant les
+/// [algorithmes](TokenizerAlgorithms) à partir d'un flux de points de code.
+///
+/// L'analyseur est lui-même un [flux](StreamIterator) de [jetons](Token),
+/// il peut donc être utilisé avec un [TokenStream](stream::prelude::TokenStream).
+///
+/// Les choix de syntaxe (commentaires, guillemets, préfixes de nombres,
+/// mots-clés, opérateurs) dépendent du [profil](LexerProfile) de l'analyseur,
+/// MikuScript par défaut.
+pub struct Tokenizer<Stream: StreamIterator> {
+	stream: Stream,
+
+	/// Les points de code à relire avant ceux du flux, après le retour en
+	/// arrière d'une récupération sur des jetons déjà analysés.
+	replay: VecDeque<Stream::Item>,
+
+	/// Les points de code consommés depuis le début du jeton actuel, en
+	/// cours d'analyse anticipée.
+	recorded: Option<Vec<Stream::Item>>,
+
+	/// Le profil du langage analysé.
+	profile: LexerProfile,
+
+	/// L'emplacement du dernier point de code consommé.
+	location: Location,
+
+	/// Le dernier point de code consommé était un U+000D CARRIAGE RETURN.
+	after_carriage_return: bool,
+
+	/// Le texte consommé depuis le début du jeton actuel.
+	consumed: String,
+
+	/// Le dernier jeton consommé.
+	current_token: Option<Token>,
+
+	/// Les jetons déjà analysés mais pas encore consommés.
+	lookahead: VecDeque<Lookahead<Stream::Item>>,
+
+	/// Les diagnostics non fatals émis pendant l'analyse.
+	diagnostics: Vec<Diagnostic>,
+
+	/// La pile des modes d'analyse. Vide en mode normal.
+	modes: Vec<LexerMode>,
+
+	#[cfg(feature = "identifier")]
+	/// La détection des identifiants confondables, désactivée par défaut.
+	confusables: Option<ConfusableDetector>,
+
+	#[cfg(feature = "identifier")]
+	/// Les identifiants vérifiés par la détection des identifiants
+	/// confondables, en cours d'analyse anticipée : leur identifiant, leur
+	/// squelette et leur emplacement.
+	checked: Vec<(String, String, Span)>,
+
+	#[cfg(feature = "keyword")]
+	/// La table des mots-clés, construite à partir du profil.
+	keywords: KeywordTable,
+}
+
+/// Un jeton analysé à l'avance, avec ce qu'il faut pour revenir juste
+/// avant ou juste après lui.
+struct Lookahead<Item> {
+	/// Le jeton analysé.
+	token: Result<Token, LexicalError>,
+
+	/// Le texte consommé par le jeton.
+	text: String,
+
+	/// Les points de code consommés par le jeton.
+	codepoints: Vec<Item>,
+
+	/// Les diagnostics émis pendant l'analyse du jeton.
+	diagnostics: Vec<Diagnostic>,
+
+	#[cfg(feature = "identifier")]
+	/// Les identifiants vérifiés par la détection des identifiants
+	/// confondables pendant l'analyse du jeton.
+	checked: Vec<(String, String, Span)>,
+
+	/// L'état de l'analyseur avant l'analyse du jeton.
+	state: LexerState,
+}
+
+/// L'état de l'analyseur entre deux jetons.
+#[derive(Debug)]
+#[derive(Clone)]
+struct LexerState {
+	location: Location,
+	after_carriage_return: bool,
+	modes: Vec<LexerMode>,
+}
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+/// Mode d'analyse de l'analyseur lexical, empilé lorsque l'analyse normale
+/// reprend à l'intérieur d'un autre jeton.
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+enum LexerMode {
+	/// À l'intérieur d'une interpolation `${...}` d'une chaîne de
+	/// caractères modèle.
+	TemplateInterpolation {
+		/// Le nombre de `{` ouverts, et pas encore fermés, dans
+		/// l'expression.
+		braces: usize,
+
+		/// L'emplacement du U+0060 GRAVE ACCENT (`) de début de la chaîne
+		/// de caractères modèle.
+		start: Location,
+	},
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl<S, U> Tokenizer<S>
+where
+	S: StreamIterator<Item = CodePoint<U>>,
+	U: CodePointInterface,
+{
+	/// Crée un nouvel analyseur lexical à partir d'un flux de points de code.
+	pub fn new(stream: S) -> Self {
+		let profile = LexerProfile::default();
+		Self {
+			stream,
+			replay: VecDeque::new(),
+			recorded: None,
+			#[cfg(feature = "keyword")]
+			keywords: KeywordTable::from(profile.keywords),
+			profile,
+			location: Location::new(),
+			after_carriage_return: false,
+			consumed: String::new(),
+			current_token: None,
+			lookahead: VecDeque::new(),
+			diagnostics: Vec::new(),
+			modes: Vec::new(),
+			#[cfg(feature = "identifier")]
+			confusables: None,
+			#[cfg(feature = "identifier")]
+			checked: Vec::new(),
+		}
+	}
+
+	/// Définit le profil du langage analysé.
+	pub fn define_profile(mut self, profile: LexerProfile) -> Self {
+		self.profile = profile;
+		#[cfg(feature = "keyword")]
+		{
+			let keywords = KeywordTable::from(self.profile.keywords);
+			self = self.define_keywords(keywords);
+		}
+		self
+	}
+
+	#[cfg(feature = "keyword")]
+	/// Remplace la table des mots-clés construite à partir du profil. À
+	/// appeler après [Tokenizer::define_profile] : lorsque le profil est
+	/// insensible à la casse ASCII, la table l'est aussi.
+	pub fn define_keywords(mut self, keywords: KeywordTable) -> Self {
+		self.keywords = keywords;
+		#[cfg(feature = "identifier")]
+		if self.profile.ascii_case_insensitive {
+			self.keywords = self.keywords.into_ascii_case_insensitive();
+		}
+		self
+	}
+
+	#[cfg(feature = "identifier")]
+	/// Active la détection des identifiants confondables (UTS #39) : les
+	/// identifiants mélangeant plusieurs écritures, ou confondables avec un
+	/// identifiant déjà rencontré par `detector`, sont signalés par des
+	/// diagnostics non fatals.
+	pub fn define_confusable_detection(
+		mut self,
+		detector: ConfusableDetector,
+	) -> Self {
+		self.confusables = Some(detector);
+		self
+	}
+
+	#[cfg(feature = "identifier")]
+	/// Retourne, et désactive, la détection des identifiants confondables,
+	/// afin de la transmettre à l'analyseur du fichier suivant d'un projet.
+	pub fn take_confusable_detection(&mut self) -> Option<ConfusableDetector> {
+		self.confusables.take()
+	}
+
+	#[cfg(feature = "keyword")]
+	/// La table des mots-clés de l'analyseur.
+	pub fn keywords(&self) -> &KeywordTable {
+		&self.keywords
+	}
+
+	/// Le profil du langage analysé.
+	pub fn profile(&self) -> &LexerProfile {
+		&self.profile
+	}
+
+	/// L'emplacement du dernier point de code consommé.
+	pub fn location(&self) -> Location {
+		self.location
+	}
+
+	/// Retourne, et oublie, les diagnostics non fatals émis depuis le
+	/// dernier appel.
+	pub fn take_diagnostics(&mut self) -> Vec<Diagnostic> {
+		core::mem::take(&mut self.diagnostics)
+	}
+
+	/// Émet un diagnostic non fatal.
+	fn warn(&mut self, error: impl Into<LexicalError>, span: Span) {
+		self.diagnostics.push(Diagnostic {
+			error: error.into(),
+			severity: Severity::Warning,
+			span,
+		});
+	}
+
+	#[cfg(feature = "identifier")]
+	/// Vérifie l'identifiant lorsque la détection des identifiants
+	/// confondables est activée, et retourne son squelette.
+	fn check_confusables(
+		&mut self,
+		identifier: &str,
+		span: Span,
+	) -> Option<String> {
+		let detector = self.confusables.as_mut()?;
+		let skeleton = ConfusableDetector::skeleton(identifier);
+		for error in detector.check(identifier, &skeleton, span) {
+			self.warn(error, span);
+		}
+		if self.recorded.is_some() {
+			self.checked
+				.push((identifier.to_owned(), skeleton.clone(), span));
+		}
+		Some(skeleton)
+	}
+
+	/// L'emplacement du prochain point de code.
+	fn next_location(&self) -> Location {
+		let mut location = self.location;
+		location.increment_column();
+		location
+	}
+
+	/// Consomme le prochain point de code et met à jour l'emplacement.
+	///
+	/// Une séquence CR LF ne compte que pour une seule ligne.
+	fn consume_codepoint(&mut self) -> CodePoint<U> {
+		let codepoint = match self.replay.pop_front() {
+			| Some(codepoint) => codepoint,
+			| None => self.stream.consume_next().unwrap_or(CodePoint::EOF),
+		};
+		if let (Some(recorded), false) =
+			(&mut self.recorded, codepoint == CodePoint::EOF)
+		{
+			recorded.push(codepoint);
+		}
+
+		match codepoint {
+			| CodePoint::EOF => {}
+			| CodePoint::Newline(unit)
+				if unit.is('\n') && self.after_carriage_return =>
+			{
+				self.location.increment_total();
+			}
+			| CodePoint::Newline(_) => {
+				self.location.increment_line();
+				self.location.column = 0;
+			}
+			| _ => self.location.increment_column(),
+		}
+
+		self.after_carriage_return =
+			matches!(codepoint, CodePoint::Newline(unit) if unit.is('\r'));
+		self.consumed.extend(codepoint.maybe_unit());
+		codepoint
+	}
+
+	/// Le prochain point de code, sans le consommer.
+	fn peek_codepoint(&mut self) -> CodePoint<U> {
+		match self.replay.front() {
+			| Some(codepoint) => *codepoint,
+			| None => self.stream.peek_next().unwrap_or(CodePoint::EOF),
+		}
+	}
+
+	/// Les `n` prochains points de code, sans les consommer. Les points de
+	/// code manquants, en fin de flux, valent [CodePoint::EOF].
+	fn peek_n_codepoints(&mut self, n: usize) -> Vec<CodePoint<U>> {
+		let mut codepoints: Vec<_> =
+			self.replay.iter().take(n).copied().collect();
+		if codepoints.len() < n {
+			codepoints.extend(
+				self.stream
+					.peek_n_next(n - codepoints.len())
+					.into_iter()
+					.map(|codepoint| codepoint.unwrap_or(CodePoint::EOF)),
+			);
+		}
+		codepoints.resize(n, CodePoint::EOF);
+		codepoints
+	}
+
+	/// L'état actuel de l'analyseur.
+	fn state(&self) -> LexerState {
+		LexerState {
+			location: self.location,
+			after_carriage_return: self.after_carriage_return,
+			modes: self.modes.clone(),
+		}
+	}
+
+	/// Analyse le prochain jeton à l'avance, en conservant son texte, ses
+	/// points de code et ses diagnostics.
+	fn next_lookahead(&mut self) -> Lookahead<CodePoint<U>> {
+		let state = self.state();
+		let diagnostics = core::mem::take(&mut self.diagnostics);
+		self.recorded = Some(Vec::new());
+		let token = self.next_token();
+		Lookahead {
+			token,
+			text: self.consumed.clone(),
+			codepoints: self.recorded.take().unwrap_or_default(),
+			diagnostics: core::mem::replace(&mut self.diagnostics, diagnostics),
+			#[cfg(feature = "identifier")]
+			checked: core::mem::take(&mut self.checked),
+			state,
+		}
+	}
+
+	/// Consomme un jeton analysé à l'avance : son texte et ses diagnostics
+	/// redeviennent ceux du jeton actuel.
+	fn consume_lookahead(
+		&mut self,
+		entry: Lookahead<CodePoint<U>>,
+	) -> Result<Token, LexicalError> {
+		self.consumed = entry.text;
+		self.diagnostics.extend(entry.diagnostics);
+		entry.token
+	}
+
+	/// Revient juste après le dernier jeton consommé : les jetons analysés
+	/// à l'avance sont oubliés et leurs points de code seront relus.
+	fn rewind_lookahead(&mut self) {
+		let Some(LexerState {
+			location,
+			after_carriage_return,
+			modes,
+		}) = self.lookahead.front().map(|entry| entry.state.clone())
+		else {
+			return;
+		};
+
+		self.location = location;
+		self.after_carriage_return = after_carriage_return;
+		self.modes = modes;
+
+		let mut codepoints = Vec::new();
+		for entry in self.lookahead.drain(..) {
+			codepoints.extend(entry.codepoints);
+			// NOTE: les identifiants de ces jetons seront vérifiés à nouveau,
+			// leurs diagnostics ayant été oubliés avec eux.
+			#[cfg(feature = "identifier")]
+			if let Some(detector) = self.confusables.as_mut() {
+				for (identifier, skeleton, span) in entry.checked {
+					detector.forget(&identifier, &skeleton, span);
+				}
+			}
+		}
+		for codepoint in codepoints.into_iter().rev() {
+			self.replay.push_front(codepoint);
+		}
+	}
+
+	/// Le point de code sous forme de caractère, `'\0'` pour NULL et EOF.
+	fn as_char(codepoint: CodePoint<U>) -> char {
+		codepoint.maybe_unit().unwrap_or_default()
+	}
+
+	/// Vérifie que les prochains points de code correspondent à un texte,
+	/// sans les consommer.
+	fn next_is(&mut self, text: &str) -> bool {
+		let length = text.chars().count();
+		self.peek_n_codepoints(length)
+			.into_iter()
+			.map(|codepoint| codepoint.maybe_unit())
+			.eq(text.chars().map(Some))
+	}
+
+	/// Le plus long texte d'une liste correspondant aux prochains points de
+	/// code.
+	fn next_longest_of<'a>(&mut self, texts: &[&'a str]) -> Option<&'a str> {
+		texts
+			.iter()
+			.copied()
+			.filter(|text| !text.is_empty() && self.next_is(text))
+			.max_by_key(|text| text.chars().count())
+	}
+
+	/// Consomme les points de code tant que le prédicat est vrai, et les
+	/// ajoute à la fin d'une chaîne de caractères.
+	fn consume_while(
+		&mut self,
+		data: &mut String,
+		mut predicate: impl FnMut(char) -> bool,
+	) {
+		while self
+			.peek_codepoint()
+			.maybe_unit()
+			.is_some_and(&mut predicate)
+		{
+			data.push(Self::as_char(self.consume_codepoint()));
+		}
+	}
+}
+
+impl<S, U> Tokenizer<S>
+where
+	S: StreamIterator<Item = CodePoint<U>>,
+	U: CodePointInterface,
+{
+	/// Analyse le prochain jeton du flux de points de code.
+	///
+	/// L'algorithme utilisé dépend des prochains points de code :
+	///   1. EOF : [Token::EOF] ;
+	///   2. saut de ligne : [Token::Newline] ;
+	///   3. espace blanc : [Token::Whitespace], les espaces blancs suivants
+	///      sont également consommés ;
+	///   4. un début de commentaire du profil :
+	///      [TokenizerAlgorithms::consume_comments] ;
+	///   5. `b'` ou `b"` : [TokenizerAlgorithms::consume_byte] ou
+	///      [TokenizerAlgorithms::consume_byte_string] ;
+	///      le délimiteur de caractère du profil :
+	///      [TokenizerAlgorithms::consume_char] ;
+	///      un guillemet du profil, ou le début d'une chaîne de caractères
+	///      brute (`r"`, `r#`) : [TokenizerAlgorithms::consume_string] ;
+	///      un U+0060 GRAVE ACCENT (`), ou le `}` fermant une interpolation :
+	///      [TokenizerAlgorithms::consume_template] ;
+	///   6. un chiffre, ou `.` suivi d'un chiffre :
+	///      [TokenizerAlgorithms::consume_numeric] ; lorsque le profil a des
+	///      dimensions, éventuellement précédés d'un signe :
+	///      [TokenizerAlgorithms::consume_numeric_token] ;
+	///   7. un point de code de début d'identifiant, ou le début d'un
+	///      identifiant CSS lorsque le profil les utilise :
+	///      [TokenizerAlgorithms::consume_boolean] puis
+	///      [TokenizerAlgorithms::consume_ident_sequence] ;
+	///   8. sinon, le plus long opérateur du profil.
+	fn next_token(&mut self) -> Result<Token, LexicalError> {
+		self.consumed.clear();
+
+		let start = self.next_location();
+		let next = self.peek_n_codepoints(2);
+
+		match next[0] {
+			| CodePoint::EOF => return Ok(Token::EOF(Span::from(start))),
+
+			| CodePoint::Newline(_) => {
+				self.consume_codepoint();
+				return Ok(Token::Newline(Span::new(start, self.location)));
+			}
+
+			| CodePoint::Whitespace(_) => {
+				while let CodePoint::Whitespace(_) = self.peek_codepoint() {
+					self.consume_codepoint();
+				}
+				return Ok(Token::Whitespace(Span::new(start, self.location)));
+			}
+
+			#[cfg(feature = "comment")]
+			| _ if self.next_is_comment() => {
+				return Ok(Token::Comment(self.consume_comments()?));
+			}
+
+			#[cfg(feature = "literal")]
+			| _ if self.next_is_byte_literal().is_some() => {
+				let literal = match self.next_is_byte_literal() {
+					| Some('"') => {
+						Literal::ByteString(self.consume_byte_string()?)
+					}
+					| _ => Literal::Byte(self.consume_byte()?),
+				};
+				return Ok(Token::Literal(literal));
+			}
+
+			#[cfg(feature = "literal")]
+			| codepoint if self.profile.char_quote == codepoint.maybe_unit() => {
+				let ch = self.consume_char()?;
+				return Ok(Token::Literal(Literal::Char(ch)));
+			}
+
+			#[cfg(feature = "literal")]
+			| codepoint
+				if self
+					.profile
+					.string_quotes
+					.contains(&Self::as_char(codepoint))
+					|| self.next_is_raw_string() =>
+			{
+				let string = self.consume_string()?;
+				return Ok(Token::Literal(Literal::String(string)));
+			}
+
+			#[cfg(feature = "literal")]
+			| CodePoint::Unit(unit)
+				if unit.as_char() == '`' && self.profile.template_strings =>
+			{
+				let template = self.consume_template()?;
+				return Ok(Token::Literal(Literal::Template(template)));
+			}
+
+			#[cfg(feature = "literal")]
+			| CodePoint::RIGHT_CURLY_BRACKET
+				if matches!(
+					self.modes.last(),
+					Some(LexerMode::TemplateInterpolation { braces: 0, .. })
+				) =>
+			{
+				let template = self.consume_template()?;
+				return Ok(Token::Literal(Literal::Template(template)));
+			}
+
+			#[cfg(feature = "literal")]
+			| _ if self.profile.numeric_dimensions
+				&& self.next_would_start_number() =>
+			{
+				return Ok(Token::Literal(self.consume_numeric_token()?));
+			}
+
+			#[cfg(feature = "literal")]
+			| codepoint
+				if codepoint.is_digit()
+					|| (codepoint == CodePoint::FULL_STOP
+						&& next[1].is_digit()) =>
+			{
+				return Ok(Token::Literal(self.consume_numeric()?));
+			}
+
+			#[cfg(feature = "identifier")]
+			| codepoint
+				if codepoint.is_ident_start()
+					|| self.profile.css_identifiers
+						&& self.next_would_start_css_ident() =>
+			{
+				#[cfg(feature = "literal")]
+				if let Some(boolean) = self.consume_boolean() {
+					return Ok(Token::Literal(Literal::Bool(boolean)));
+				}
+
+				return match self.consume_ident_sequence() {
+					| Ok(identifier) => Ok(Token::Identifier(identifier)),
+					#[cfg(feature = "keyword")]
+					| Err(IdentifierParseError::IsKeyword {
+						found,
+						spelling,
+					}) => Ok(Token::Keyword(KeywordOutput {
+						keyword: found,
+						spelling,
+						span: Span::new(start, self.location),
+					})),
+					| Err(err) => Err(err.into()),
+				};
+			}
+
+			| _ => {}
+		}
+
+		if let Some(LexerMode::TemplateInterpolation { braces, .. }) =
+			self.modes.last_mut()
+		{
+			match next[0] {
+				| CodePoint::LEFT_CURLY_BRACKET => *braces += 1,
+				| CodePoint::RIGHT_CURLY_BRACKET => {
+					*braces = braces.saturating_sub(1)
+				}
+				| _ => {}
+			}
+		}
+
+		#[cfg(feature = "delimiter")]
+		return self.consume_delimiter().map(Token::Delimiter);
+
+		#[cfg(not(feature = "delimiter"))]
+		{
+			self.consume_codepoint();
+			Err(LexicalError::EOS)
+		}
+	}
+
+	#[cfg(feature = "comment")]
+	/// Vérifie que les prochains points de code commencent un commentaire
+	/// du [profil](LexerProfile).
+	fn next_is_comment(&mut self) -> bool {
+		let line_comments = self.profile.line_comments;
+		let block_comments = self.profile.block_comments;
+		self.next_longest_of(line_comments).is_some()
+			|| block_comments.iter().any(|(start, _)| self.next_is(start))
+	}
+
+	#[cfg(feature = "literal")]
+	/// Consomme le contenu d'une chaîne de caractères de plusieurs lignes,
+	/// jusqu'au `"""` de fin inclus, et retourne sa valeur.
+	///
+	/// Les sauts de ligne (LF, CR LF, CR) sont normalisés en LF. Lorsque le
+	/// `"""` de début est suivi d'un saut de ligne, ce saut de ligne est
+	/// ignoré. Lorsque le `"""` de fin est seul sur sa ligne, cette ligne
+	/// est ignorée et son indentation est retirée du début de chaque ligne.
+	fn consume_triple_quoted(
+		&mut self,
+		span: Span,
+	) -> Result<String, StringParseError> {
+		// Chaque ligne : son indentation, telle qu'elle a été écrite, et la
+		// suite de son contenu, séquences d'échappement interprétées.
+		let mut lines = vec![(String::new(), String::new())];
+
+		loop {
+			let (indent, content) = lines.last_mut().expect("une ligne");
+			match self.peek_codepoint() {
+				| CodePoint::EOF => {
+					return Err(StringParseError::Unterminated {
+						line: span.start.line,
+						column: span.start.column,
+					});
+				}
+				| CodePoint::QUOTATION_MARK if self.next_is(r#"""""#) => {
+					(0..3).for_each(|_| {
+						self.consume_codepoint();
+					});
+					break;
+				}
+				| CodePoint::Newline(unit) => {
+					self.consume_codepoint();
+					if unit.as_char() == '\r'
+						&& self.peek_codepoint().maybe_unit() == Some('\n')
+					{
+						self.consume_codepoint();
+					}
+					lines.push((String::new(), String::new()));
+				}
+				| CodePoint::Whitespace(_) if content.is_empty() => {
+					indent.push(Self::as_char(self.consume_codepoint()));
+				}
+				| CodePoint::REVERSE_SOLIDUS if self.profile.string_escapes => {
+					self.consume_escape(content, false)?;
+				}
+				| _ => content.push(Self::as_char(self.consume_codepoint())),
+			}
+		}
+
+		if lines.len() > 1 && lines[0].0.is_empty() && lines[0].1.is_empty() {
+			lines.remove(0);
+		}
+
+		let mut closing_indent = String::new();
+		if lines.len() > 1 && lines.last().is_some_and(|(_, c)| c.is_empty()) {
+			closing_indent = lines.pop().map(|(indent, _)| indent).unwrap();
+		}
+
+		let lines: Vec<_> = lines
+			.into_iter()
+			.map(|(indent, content)| {
+				let indent = match indent.strip_prefix(closing_indent.as_str())
+				{
+					| Some(indent) => indent.to_owned(),
+					| None if content.is_empty() => String::new(),
+					| None => indent,
+				};
+				indent + &content
+			})
+			.collect();
+
+		Ok(lines.join("\n"))
+	}
+
+	#[cfg(feature = "literal")]
+	/// Consomme les chiffres de base `radix` et les ajoute à la fin de
+	/// `data`, sans leurs séparateurs U+005F LOW LINE (_). Un séparateur doit
+	/// être placé entre deux chiffres.
+	fn consume_digits(
+		&mut self,
+		data: &mut String,
+		radix: u32,
+	) -> Result<(), IntegerParseError> {
+		// L'emplacement du séparateur qui attend son chiffre suivant.
+		let mut separator = None;
+
+		loop {
+			match self.peek_codepoint().maybe_unit() {
+				| Some(unit) if unit.is_digit(radix) => {
+					self.consume_codepoint();
+					data.push(unit);
+					separator = None;
+				}
+				| Some('_') if self.profile.numeric_separators => {
+					let location = self.next_location();
+					self.consume_codepoint();
+					let after_digit = data
+						.chars()
+						.last()
+						.is_some_and(|ch| ch.is_digit(radix));
+					if separator.is_some() || !after_digit {
+						return Err(IntegerParseError::MisplacedSeparator {
+							span: Span::from(location),
+						});
+					}
+					separator = Some(location);
+				}
+				| _ => break,
+			}
+		}
+
+		if let Some(location) = separator {
+			return Err(IntegerParseError::MisplacedSeparator {
+				span: Span::from(location),
+			});
+		}
+
+		// Un chiffre décimal qui n'appartient pas à la base : `0b102`.
+		match self.peek_codepoint() {
+			| codepoint if codepoint.is_digit() => {
+				Err(IntegerParseError::InvalidDigit {
+					found: Self::as_char(codepoint),
+					radix,
+					span: Span::from(self.next_location()),
+				})
+			}
+			| _ => Ok(()),
+		}
+	}
+
+	#[cfg(feature = "literal")]
+	/// Vérifie que les trois prochains points de code commencent un nombre
+	/// CSS : un chiffre, ou `.` suivi d'un chiffre, éventuellement précédés
+	/// d'un signe `+` ou `-`.
+	fn next_would_start_number(&mut self) -> bool {
+		let next = self.peek_n_codepoints(3);
+		let (first, rest) = match next[0].maybe_unit() {
+			| Some('+' | '-') => (next[1], &next[2..]),
+			| _ => (next[0], &next[1..]),
+		};
+
+		first.is_digit()
+			|| first == CodePoint::FULL_STOP
+				&& rest.first().is_some_and(CodePoint::is_digit)
+	}
+
+	#[cfg(any(feature = "identifier", feature = "literal"))]
+	/// Vérifie que les trois prochains points de code commencent un
+	/// identifiant CSS : un point de code de début d'identifiant, une
+	/// séquence d'échappement, ou `-` suivi de l'un d'eux ou d'un autre `-`.
+	fn next_would_start_css_ident(&mut self) -> bool {
+		let next = self.peek_n_codepoints(3);
+		let is_escape = |codepoints: &[CodePoint<U>]| {
+			codepoints[0] == CodePoint::REVERSE_SOLIDUS
+				&& !matches!(codepoints[1], CodePoint::Newline(_))
+		};
+
+		match next[0].maybe_unit() {
+			| Some('-') => {
+				next[1].is_css_ident_start()
+					|| next[1].maybe_unit() == Some('-')
+					|| is_escape(&next[1..])
+			}
+			| _ => next[0].is_css_ident_start() || is_escape(&next),
+		}
+	}
+
+	#[cfg(any(feature = "identifier", feature = "literal"))]
+	/// Consomme un identifiant CSS, et remplace ses séquences d'échappement
+	/// par les points de code qu'elles représentent.
+	///
+	/// Une séquence d'échappement est un U+005C REVERSE SOLIDUS (\) suivi
+	/// soit de 1 à 6 chiffres hexadécimaux et d'un espace blanc facultatif
+	/// (`\70 x`), soit de n'importe quel point de code autre qu'un saut de
+	/// ligne (`\-`). Un point de code nul, substitut ou hors limite est
+	/// remplacé par U+FFFD REPLACEMENT CHARACTER.
+	fn consume_css_ident(&mut self) -> String {
+		let mut name = String::new();
+
+		loop {
+			let next = self.peek_n_codepoints(2);
+			match next[0] {
+				| codepoint if codepoint.is_ident() => {
+					name.push(Self::as_char(self.consume_codepoint()));
+				}
+				| CodePoint::REVERSE_SOLIDUS
+					if !matches!(next[1], CodePoint::Newline(_)) =>
+				{
+					self.consume_codepoint();
+
+					let mut hex = String::new();
+					while hex.len() < 6
+						&& self
+							.peek_codepoint()
+							.maybe_unit()
+							.is_some_and(|unit| unit.is_ascii_hexdigit())
+					{
+						hex.push(Self::as_char(self.consume_codepoint()));
+					}
+
+					if hex.is_empty() {
+						name.push(match self.peek_codepoint() {
+							| CodePoint::EOF => char::REPLACEMENT_CHARACTER,
+							| _ => Self::as_char(self.consume_codepoint()),
+						});
+						continue;
+					}
+
+					if let CodePoint::Whitespace(_) = self.peek_codepoint() {
+						self.consume_codepoint();
+					}
+					let value = u32::from_str_radix(&hex, 16)
+						.expect("au plus six chiffres hexadécimaux");
+					name.push(
+						char::from_u32(value)
+							.filter(|ch| *ch != '\0')
+							.unwrap_or(char::REPLACEMENT_CHARACTER),
+					);
+				}
+				| _ => break,
+			}
+		}
+
+		name
+	}
+
+	#[cfg(feature = "literal")]
+	/// Vérifie que les prochains points de code commencent un octet (`b'`) ou
+	/// une chaîne d'octets (`b"`). Retourne le délimiteur.
+	fn next_is_byte_literal(&mut self) -> Option<char> {
+		if !self.profile.byte_strings || !self.next_is("b") {
+			return None;
+		}
+
+		let quote = self.peek_n_codepoints(2)[1].maybe_unit()?;
+		(quote == '"' || Some(quote) == self.profile.char_quote)
+			.then_some(quote)
+	}
+
+	#[cfg(feature = "literal")]
+	/// Consomme le préfixe `b`, le délimiteur `quote`, puis le contenu d'un
+	/// octet littéral jusqu'au délimiteur de fin inclus. Retourne les octets
+	/// et le texte source du contenu.
+	fn consume_bytes(
+		&mut self,
+		quote: char,
+	) -> Result<(Vec<u8>, String), ByteParseError> {
+		let start = self.next_location();
+		if self.next_is_byte_literal() != Some(quote) {
+			return Err(ByteParseError::IsNot {
+				found: Self::as_char(self.peek_codepoint()),
+			});
+		}
+
+		self.consume_codepoint();
+		self.consume_codepoint();
+		let offset = self.consumed.len();
+		let mut data = String::new();
+
+		loop {
+			match self.peek_codepoint() {
+				| CodePoint::EOF | CodePoint::Newline(_) => {
+					return Err(ByteParseError::Unterminated {
+						line: start.line,
+						column: start.column,
+					});
+				}
+				| codepoint if codepoint.maybe_unit() == Some(quote) => break,
+				| CodePoint::REVERSE_SOLIDUS if self.profile.string_escapes => {
+					self.consume_escape(&mut data, true)
+						.map_err(ByteParseError::Escape)?;
+				}
+				| codepoint if !Self::as_char(codepoint).is_ascii() => {
+					let location = self.next_location();
+					self.consume_codepoint();
+					return Err(ByteParseError::NonAscii {
+						found: Self::as_char(codepoint),
+						span: Span::new(location, self.location),
+					});
+				}
+				| _ => data.push(Self::as_char(self.consume_codepoint())),
+			}
+		}
+
+		let raw = self.consumed[offset..].to_owned();
+		self.consume_codepoint();
+
+		// NOTE: les séquences d'échappement d'octets produisent au plus
+		// U+00FF, chaque caractère tient donc sur un octet.
+		let bytes = data.chars().map(|ch| ch as u8).collect();
+		Ok((bytes, raw))
+	}
+
+	#[cfg(feature = "literal")]
+	/// Vérifie que les prochains points de code commencent une chaîne de
+	/// caractères brute : `r`, suivi de zéro ou plusieurs `#`, suivi de `"`.
+	fn next_is_raw_string(&mut self) -> bool {
+		if !self.profile.raw_strings || !self.next_is("r") {
+			return false;
+		}
+
+		let mut n = 2;
+		loop {
+			match self.peek_n_codepoints(n)[n - 1] {
+				| CodePoint::NUMBER_SIGN => n += 1,
+				| CodePoint::QUOTATION_MARK => return true,
+				| _ => return false,
+			}
+		}
+	}
+
+	#[cfg(feature = "literal")]
+	/// Consomme une séquence d'échappement d'une chaîne de caractères, et
+	/// ajoute le caractère qu'elle représente à la fin de `data`.
+	///
+	/// Une continuation de ligne (`\` suivi d'un saut de ligne) ignore le
+	/// saut de ligne ainsi que les espaces blancs de la ligne suivante.
+	///
+	/// Dans un octet littéral (`bytes`), `\xHH` accepte les valeurs jusqu'à
+	/// `0xFF` et `\u{...}` n'est pas autorisé.
+	fn consume_escape(
+		&mut self,
+		data: &mut String,
+		bytes: bool,
+	) -> Result<(), StringParseError> {
+		let start = self.next_location();
+		self.consume_codepoint();
+
+		let found = match self.peek_codepoint() {
+			| CodePoint::EOF => return Ok(()),
+			| CodePoint::Newline(_) => {
+				self.consume_codepoint();
+				if self.after_carriage_return
+					&& matches!(
+						self.peek_codepoint(),
+						CodePoint::Newline(unit) if unit.is('\n')
+					) {
+					self.consume_codepoint();
+				}
+				while let CodePoint::Whitespace(_) = self.peek_codepoint() {
+					self.consume_codepoint();
+				}
+				return Ok(());
+			}
+			| codepoint => {
+				self.consume_codepoint();
+				Self::as_char(codepoint)
+			}
+		};
+
+		let invalid = |tokenizer: &Self| StringParseError::InvalidEscape {
+			found,
+			span: Span::new(start, tokenizer.location),
+		};
+
+		let ch = match found {
+			| 'n' => '\n',
+			| 't' => '\t',
+			| 'r' => '\r',
+			| '\\' => '\\',
+			| '"' => '"',
+			| '\'' => '\'',
+			| '0' => '\0',
+			| '`' => '`',
+			| '$' => '$',
+			| 'x' => {
+				let mut hex = String::new();
+				for _ in 0..2 {
+					match self.peek_codepoint().maybe_unit() {
+						| Some(unit) if unit.is_ascii_hexdigit() => {
+							self.consume_codepoint();
+							hex.push(unit);
+						}
+						| _ => return Err(invalid(self)),
+					}
+				}
+
+				let value = u32::from_str_radix(&hex, 16)
+					.expect("deux chiffres hexadécimaux");
+				if value > 0x7F && !bytes {
+					return Err(StringParseError::OutOfRangeCodePoint {
+						value,
+						span: Span::new(start, self.location),
+					});
+				}
+				char::from(value as u8)
+			}
+			| 'u' if !bytes => {
+				if self.peek_codepoint() != CodePoint::LEFT_CURLY_BRACKET {
+					return Err(invalid(self));
+				}
+				self.consume_codepoint();
+
+				let mut hex = String::new();
+				self.consume_while(&mut hex, |unit| unit.is_ascii_hexdigit());
+				if hex.is_empty()
+					|| hex.len() > 6
+					|| self.peek_codepoint() != CodePoint::RIGHT_CURLY_BRACKET
+				{
+					return Err(invalid(self));
+				}
+				self.consume_codepoint();
+
+				let value = u32::from_str_radix(&hex, 16)
+					.expect("au plus six chiffres hexadécimaux");
+				let span = Span::new(start, self.location);
+				match char::from_u32(value) {
+					| Some(ch) => ch,
+					| None if (0xD800..=0xDFFF).contains(&value) => {
+						return Err(StringParseError::LoneSurrogate {
+							value,
+							span,
+						});
+					}
+					| None => {
+						return Err(StringParseError::OutOfRangeCodePoint {
+							value,
+							span,
+						});
+					}
+				}
+			}
+			| _ => return Err(invalid(self)),
+		};
+
+		data.push(ch);
+		Ok(())
+	}
+
+	#[cfg(feature = "comment")]
+	/// Émet l'erreur non fatale `eof-in-comment` d'un commentaire HTML.
+	fn warn_eof_in_comment(&mut self) {
+		let span = Span::from(self.next_location());
+		self.warn(CommentParseError::EofInComment, span);
+	}
+
+	#[cfg(feature = "delimiter")]
+	/// Consomme le plus long délimiteur possible parmi les opérateurs du
+	/// [profil](LexerProfile).
+	///
+	/// Lorsque le point de code n'est pas un délimiteur valide, il est tout
+	/// de même consommé, afin de toujours progresser dans le flux.
+	fn consume_delimiter(&mut self) -> Result<DelimiterOutput, LexicalError> {
+		let start = self.next_location();
+		let operators = self.profile.operators;
+
+		let Some(sign) = self.next_longest_of(operators) else {
+			let codepoint = self.consume_codepoint();
+			return Err(DelimiterParseError::Invalid {
+				found: codepoint
+					.maybe_unit()
+					.map(String::from)
+					.unwrap_or_default(),
+			}
+			.into());
+		};
+
+		let length = sign.chars().count();
+		let next = self.peek_n_codepoints(3);
+		let delimiter = match (length, next.as_slice()) {
+			| (3, [a, b, c]) => Delimiter::try_from([*a, *b, *c]),
+			| (2, [a, b, _]) => Delimiter::try_from([*a, *b]),
+			| (_, [a, ..]) => Delimiter::try_from(*a),
+			| (_, []) => unreachable!(),
+		};
+
+		(0..length).for_each(|_| {
+			self.consume_codepoint();
+		});
+
+		Ok(DelimiterOutput {
+			delimiter: delimiter?,
+			span: Span::new(start, self.location),
+		})
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl<S, U> TokenizerAlgorithms for Tokenizer<S>
+where
+	S: StreamIterator<Item = CodePoint<U>>,
+	U: CodePointInterface,
+{
+	#[cfg(feature = "comment")]
+	fn consume_comments(&mut self) -> Result<CommentOutput, CommentParseError> {
+		let mut state = CommentState::default();
+		let mut output = CommentOutput {
+			data: String::new(),
+			ty: CommentType::default(),
+			span: Span::from(self.next_location()),
+		};
+
+		// Le début et la fin du commentaire de plusieurs lignes.
+		let mut opening = "";
+		let mut ending: Option<&'static str> = None;
+
+		loop {
+			match state {
+				| CommentState::Initial => {
+					let line_comments = self.profile.line_comments;
+					let block_comments = self.profile.block_comments;
+
+					let line = self.next_longest_of(line_comments);
+					let block = block_comments
+						.iter()
+						.filter(|(start, _)| self.next_is(start))
+						.max_by_key(|(start, _)| start.chars().count());
+
+					opening = match (line, block) {
+						| (Some(line), Some((start, end)))
+							if start.len() > line.len() =>
+						{
+							ending.replace(end);
+							start
+						}
+						| (Some(line), _) => line,
+						| (None, Some((start, end))) => {
+							ending.replace(end);
+							start
+						}
+						| (None, None) => return Err(CommentParseError::IsNot),
+					};
+
+					opening.chars().for_each(|_| {
+						self.consume_codepoint();
+					});
+					state.switch(CommentState::Start);
+				}
+
+				| CommentState::Start => {
+					let next = self.peek_n_codepoints(2);
+					let doc = self.profile.doc_comments;
+					let ty = match (opening, next[0], next[1]) {
+						| ("//", CodePoint::SOLIDUS, after)
+							if doc && after != CodePoint::SOLIDUS =>
+						{
+							CommentType::OuterDocLine
+						}
+						| ("//", CodePoint::EXCLAMATION_MARK, _) if doc => {
+							CommentType::InnerDocLine
+						}
+						| ("/*", CodePoint::ASTERISK, after)
+							if doc
+								&& !matches!(
+									after,
+									CodePoint::ASTERISK | CodePoint::SOLIDUS
+								) =>
+						{
+							CommentType::OuterDocBlock
+						}
+						| ("/*", CodePoint::EXCLAMATION_MARK, _) if doc => {
+							CommentType::InnerDocBlock
+						}
+						| _ if ending.is_none() => CommentType::Line,
+						| _ => CommentType::Block,
+					};
+
+					if ty.is_doc() {
+						self.consume_codepoint();
+					}
+
+					output.ty.define(ty);
+					match ending {
+						| None => state.switch(CommentState::Singleline),
+						| Some(_)
+							if self.profile.html_comments
+								&& opening == "<!--" =>
+						{
+							state.switch(CommentState::HtmlCommentStart)
+						}
+						| Some(_) => {
+							state.switch(CommentState::Multiline { depth: 0 })
+						}
+					}
+				}
+
+				| CommentState::Singleline => match self.peek_codepoint() {
+					| CodePoint::EOF => {
+						state.switch(CommentState::SinglelineEnd)
+					}
+					| CodePoint::Newline(_) => {
+						self.consume_codepoint();
+						state.switch(CommentState::SinglelineEnd);
+					}
+					| _ => output
+						.data
+						.push(Self::as_char(self.consume_codepoint())),
+				},
+
+				| CommentState::Multiline { depth } => {
+					let ending = ending.unwrap_or_default();
+					match self.peek_codepoint() {
+						| CodePoint::EOF => {
+							return Err(CommentParseError::Unterminated {
+								line: output.span.start.line,
+								column: output.span.start.column,
+							});
+						}
+						| _ if self.profile.nested_block_comments
+							&& self.next_is(opening) =>
+						{
+							opening.chars().for_each(|_| {
+								output.data.push(Self::as_char(
+									self.consume_codepoint(),
+								));
+							});
+							state.switch(CommentState::Multiline {
+								depth: depth + 1,
+							});
+						}
+						| codepoint
+							if codepoint.maybe_unit()
+								== ending.chars().next() =>
+						{
+							state.switch(
+								CommentState::MultilineAfterFirstCodePoint {
+									depth,
+								},
+							);
+						}
+						| _ => output
+							.data
+							.push(Self::as_char(self.consume_codepoint())),
+					}
+				}
+
+				| CommentState::MultilineAfterFirstCodePoint { depth } => {
+					let ending = ending.unwrap_or_default();
+					if !self.next_is(ending) {
+						output
+							.data
+							.push(Self::as_char(self.consume_codepoint()));
+						state.switch(CommentState::Multiline { depth });
+						continue;
+					}
+
+					if depth == 0 {
+						ending.chars().for_each(|_| {
+							self.consume_codepoint();
+						});
+						state.switch(CommentState::MultilineEnd);
+					} else {
+						ending.chars().for_each(|_| {
+							output
+								.data
+								.push(Self::as_char(self.consume_codepoint()));
+						});
+						state.switch(CommentState::Multiline {
+							depth: depth - 1,
+						});
+					}
+				}
+
+				| CommentState::HtmlCommentStart => match self.peek_codepoint()
+				{
+					| CodePoint::HYPHEN_MINUS => {
+						self.consume_codepoint();
+						state.switch(CommentState::HtmlCommentStartDash);
+					}
+					| CodePoint::GREATER_THAN_SIGN => {
+						let span = Span::from(self.next_location());
+						self.consume_codepoint();
+						self.warn(
+							CommentParseError::AbruptClosingOfEmptyComment,
+							span,
+						);
+						state.switch(CommentState::MultilineEnd);
+					}
+					| _ => state.switch(CommentState::HtmlComment),
+				},
+
+				| CommentState::HtmlCommentStartDash => {
+					match self.peek_codepoint() {
+						| CodePoint::HYPHEN_MINUS => {
+							self.consume_codepoint();
+							state.switch(CommentState::HtmlCommentEnd);
+						}
+						| CodePoint::GREATER_THAN_SIGN => {
+							let span = Span::from(self.next_location());
+							self.consume_codepoint();
+							self.warn(
+								CommentParseError::AbruptClosingOfEmptyComment,
+								span,
+							);
+							state.switch(CommentState::MultilineEnd);
+						}
+						| CodePoint::EOF => {
+							self.warn_eof_in_comment();
+							state.switch(CommentState::MultilineEnd);
+						}
+						| _ => {
+							output.data.push('-');
+							state.switch(CommentState::HtmlComment);
+						}
+					}
+				}
+
+				| CommentState::HtmlComment => match self.peek_codepoint() {
+					| CodePoint::LESS_THAN_SIGN => {
+						output
+							.data
+							.push(Self::as_char(self.consume_codepoint()));
+						state.switch(CommentState::HtmlCommentLessThanSign);
+					}
+					| CodePoint::HYPHEN_MINUS => {
+						self.consume_codepoint();
+						state.switch(CommentState::HtmlCommentEndDash);
+					}
+					| CodePoint::NULL => {
+						let span = Span::from(self.next_location());
+						self.consume_codepoint();
+						self.warn(
+							CommentParseError::UnexpectedNullCharacter,
+							span,
+						);
+						output.data.push(char::REPLACEMENT_CHARACTER);
+					}
+					| CodePoint::EOF => {
+						self.warn_eof_in_comment();
+						state.switch(CommentState::MultilineEnd);
+					}
+					| _ => output
+						.data
+						.push(Self::as_char(self.consume_codepoint())),
+				},
+
+				| CommentState::HtmlCommentLessThanSign => {
+					match self.peek_codepoint() {
+						| CodePoint::EXCLAMATION_MARK => {
+							output
+								.data
+								.push(Self::as_char(self.consume_codepoint()));
+							state.switch(
+								CommentState::HtmlCommentLessThanSignBang,
+							);
+						}
+						| CodePoint::LESS_THAN_SIGN => {
+							output
+								.data
+								.push(Self::as_char(self.consume_codepoint()));
+						}
+						| _ => state.switch(CommentState::HtmlComment),
+					}
+				}
+
+				| CommentState::HtmlCommentLessThanSignBang => {
+					match self.peek_codepoint() {
+						| CodePoint::HYPHEN_MINUS => {
+							self.consume_codepoint();
+							state.switch(
+								CommentState::HtmlCommentLessThanSignBangDash,
+							);
+						}
+						| _ => state.switch(CommentState::HtmlComment),
+					}
+				}
+
+				| CommentState::HtmlCommentLessThanSignBangDash => {
+					match self.peek_codepoint() {
+						| CodePoint::HYPHEN_MINUS => {
+							self.consume_codepoint();
+							state.switch(CommentState::HtmlCommentLessThanSignBangDashDash);
+						}
+						| _ => state.switch(CommentState::HtmlCommentEndDash),
+					}
+				}
+
+				| CommentState::HtmlCommentLessThanSignBangDashDash => {
+					match self.peek_codepoint() {
+						| CodePoint::GREATER_THAN_SIGN | CodePoint::EOF => {}
+						| _ => {
+							// Le `<!--` commence à 3 points de code du
+							// dernier point de code consommé.
+							let mut start = self.location;
+							start.column = start.column.saturating_sub(3);
+							self.warn(
+								CommentParseError::NestedComment,
+								Span::new(start, self.location),
+							);
+						}
+					}
+					state.switch(CommentState::HtmlCommentEnd);
+				}
+
+				| CommentState::HtmlCommentEndDash => {
+					match self.peek_codepoint() {
+						| CodePoint::HYPHEN_MINUS => {
+							self.consume_codepoint();
+							state.switch(CommentState::HtmlCommentEnd);
+						}
+						| CodePoint::EOF => {
+							self.warn_eof_in_comment();
+							state.switch(CommentState::MultilineEnd);
+						}
+						| _ => {
+							output.data.push('-');
+							state.switch(CommentState::HtmlComment);
+						}
+					}
+				}
+
+				| CommentState::HtmlCommentEnd => match self.peek_codepoint() {
+					| CodePoint::GREATER_THAN_SIGN => {
+						self.consume_codepoint();
+						state.switch(CommentState::MultilineEnd);
+					}
+					| CodePoint::EXCLAMATION_MARK => {
+						self.consume_codepoint();
+						state.switch(CommentState::HtmlCommentEndBang);
+					}
+					| CodePoint::HYPHEN_MINUS => {
+						self.consume_codepoint();
+						output.data.push('-');
+					}
+					| CodePoint::EOF => {
+						self.warn_eof_in_comment();
+						state.switch(CommentState::MultilineEnd);
+					}
+					| _ => {
+						output.data.push_str("--");
+						state.switch(CommentState::HtmlComment);
+					}
+				},
+
+				| CommentState::HtmlCommentEndBang => {
+					match self.peek_codepoint() {
+						| CodePoint::HYPHEN_MINUS => {
+							self.consume_codepoint();
+							output.data.push_str("--!");
+							state.switch(CommentState::HtmlCommentEndDash);
+						}
+						| CodePoint::GREATER_THAN_SIGN => {
+							let mut start = self.location;
+							start.column = start.column.saturating_sub(2);
+							self.consume_codepoint();
+							self.warn(
+								CommentParseError::IncorrectlyClosedComment,
+								Span::new(start, self.location),
+							);
+							state.switch(CommentState::MultilineEnd);
+						}
+						| CodePoint::EOF => {
+							self.warn_eof_in_comment();
+							state.switch(CommentState::MultilineEnd);
+						}
+						| _ => {
+							output.data.push_str("--!");
+							state.switch(CommentState::HtmlComment);
+						}
+					}
+				}
+
+				| CommentState::SinglelineEnd | CommentState::MultilineEnd => {
+					if output.ty.is_doc() {
+						output.clean_doc();
+					}
+					output.span.end = self.location;
+					return Ok(output);
+				}
+			}
+		}
+	}
+
+	#[cfg(feature = "identifier")]
+	fn consume_ident_sequence(
+		&mut self,
+	) -> Result<IdentifierOutput, IdentifierParseError> {
+		let css = self.profile.css_identifiers;
+		let next = self.peek_codepoint();
+		let starts_ident =
+			next.is_ident_start() || css && self.next_would_start_css_ident();
+		if !starts_ident {
+			return Err(IdentifierParseError::IsNot {
+				found: Self::as_char(next),
+			});
+		}
+
+		let start = self.next_location();
+		let mut identifier = String::new();
+		// NOTE: le mot-clé est reconnu pendant la lecture de l'identifiant.
+		#[cfg(feature = "keyword")]
+		let mut matcher = if self.profile.ascii_case_insensitive {
+			KeywordMatcher::ignore_ascii_case()
+		} else {
+			KeywordMatcher::new()
+		};
+		if css {
+			// NOTE: les séquences d'échappement CSS ne sont connues qu'une
+			// fois l'identifiant lu.
+			identifier = self.consume_css_ident();
+			#[cfg(feature = "keyword")]
+			for ch in identifier.chars() {
+				matcher.push(ch);
+			}
+		} else {
+			self.consume_while(&mut identifier, |unit| {
+				let is_ident = unit.is_ident_after_start();
+				#[cfg(feature = "keyword")]
+				if is_ident {
+					matcher.push(unit);
+				}
+				is_ident
+			});
+		}
+
+		// NOTE: les identifiants sont comparés sous leur forme NFC : `é`
+		// (U+00E9) et `e` suivi de U+0301 COMBINING ACUTE ACCENT sont le
+		// même identifiant.
+		if !unicode_normalization::is_nfc(&identifier) {
+			identifier = identifier.nfc().collect();
+			#[cfg(feature = "keyword")]
+			{
+				matcher = if self.profile.ascii_case_insensitive {
+					KeywordMatcher::ignore_ascii_case()
+				} else {
+					KeywordMatcher::new()
+				};
+				for ch in identifier.chars() {
+					matcher.push(ch);
+				}
+			}
+		}
+
+		let normalized = self
+			.profile
+			.ascii_case_insensitive
+			.then(|| identifier.to_ascii_lowercase());
+
+		#[cfg(feature = "keyword")]
+		let keyword = match self
+			.keywords
+			.get_matched(&matcher, normalized.as_deref().unwrap_or(&identifier))
+		{
+			| Some(entry) if entry.kind == KeywordKind::Reserved => {
+				return Err(IdentifierParseError::IsKeyword {
+					found: entry.keyword,
+					spelling: identifier,
+				});
+			}
+			| entry => entry.map(|entry| entry.keyword),
+		};
+
+		let span = Span::new(start, self.location);
+		let skeleton = self.check_confusables(&identifier, span);
+
+		Ok(IdentifierOutput {
+			identifier,
+			normalized,
+			skeleton,
+			#[cfg(feature = "keyword")]
+			keyword,
+			span,
+		})
+	}
+
+	#[cfg(feature = "literal")]
+	fn consume_numeric(&mut self) -> Result<Literal, LiteralParseError> {
+		let mut state = IntegerState::default();
+		let start = self.next_location();
+		let mut data = String::new();
+		let mut prefix = None;
+		let mut exponent = None;
+		let mut suffix = None;
+
+		let is_decimal_float = |codepoints: &[CodePoint<U>]| match codepoints {
+			| [CodePoint::FULL_STOP, next] => next.is_digit(),
+			| _ => false,
+		};
+
+		// `e` ou `E`, suivi d'un chiffre, ou d'un signe puis d'un chiffre.
+		let is_exponent = |codepoints: &[CodePoint<U>]| {
+			let unit = |n: usize| codepoints[n].maybe_unit();
+			matches!(unit(0), Some('e' | 'E'))
+				&& (codepoints[1].is_digit()
+					|| matches!(unit(1), Some('+' | '-'))
+						&& codepoints[2].is_digit())
+		};
+
+		loop {
+			match state {
+				| IntegerState::Initial => {
+					let next = self.peek_n_codepoints(2);
+					match next[0] {
+						| codepoint if codepoint.maybe_unit() == Some('0') => {
+							data.push(Self::as_char(self.consume_codepoint()));
+							state.switch(IntegerState::DecimalZero);
+						}
+						| codepoint if codepoint.is_digit() => {
+							state.switch(IntegerState::DecimalInteger);
+						}
+						| _ if is_decimal_float(&next) => {
+							state.switch(IntegerState::DecimalFloat);
+						}
+						| _ => state.switch(IntegerState::IsNot),
+					}
+				}
+
+				| IntegerState::DecimalZero => {
+					let next = self.peek_n_codepoints(2);
+					prefix =
+						self.profile.numeric_prefixes.iter().copied().find(
+							|prefix| {
+								next[0].maybe_unit() == Some(prefix.prefix())
+							},
+						);
+					match prefix {
+						| Some(prefix) => {
+							self.consume_codepoint();
+							data.clear();
+							state.switch(match prefix {
+								| NumericPrefix::Hexadecimal => {
+									IntegerState::Hexadecimal
+								}
+								| NumericPrefix::Binary => IntegerState::Binary,
+								| NumericPrefix::Octal => IntegerState::Octal,
+							});
+						}
+						| None if !self.profile.numeric_leading_zeros
+							&& (next[0].is_digit()
+								|| next[0].maybe_unit() == Some('_')) =>
+						{
+							return Err(IntegerParseError::LeadingZero {
+								span: Span::from(start),
+							}
+							.into());
+						}
+						| None => state.switch(IntegerState::DecimalInteger),
+					}
+				}
+
+				| IntegerState::DecimalInteger => {
+					self.consume_digits(&mut data, 10)?;
+					let next = self.peek_n_codepoints(2);
+					if is_decimal_float(&next) {
+						state.switch(IntegerState::DecimalFloat);
+					} else if self.profile.numeric_trailing_dot
+						&& next[0] == CodePoint::FULL_STOP
+						&& next[1] != CodePoint::FULL_STOP
+						&& !next[1].is_ident_start()
+					{
+						data.push(Self::as_char(self.consume_codepoint()));
+						state.switch(IntegerState::Suffix);
+					} else {
+						state.switch(IntegerState::Exponent);
+					}
+				}
+
+				| IntegerState::DecimalFloat => {
+					data.push(Self::as_char(self.consume_codepoint()));
+					self.consume_digits(&mut data, 10)?;
+
+					// NOTE: en CSS, `1.2.3` est une suite de deux nombres,
+					// `1.2` et `.3`.
+					if !self.profile.numeric_dimensions
+						&& is_decimal_float(&self.peek_n_codepoints(2))
+					{
+						return Err(IntegerParseError::MultipleDecimalPoints {
+							span: Span::from(self.next_location()),
+						}
+						.into());
+					}
+					state.switch(IntegerState::Exponent);
+				}
+
+				| IntegerState::Exponent => {
+					if is_exponent(&self.peek_n_codepoints(3)) {
+						let location = self.next_location();
+						self.consume_codepoint();
+
+						let mut digits = String::new();
+						if let Some(sign @ ('+' | '-')) =
+							self.peek_codepoint().maybe_unit()
+						{
+							self.consume_codepoint();
+							digits.push(sign);
+						}
+						self.consume_digits(&mut digits, 10)?;
+
+						let span = Span::new(location, self.location);
+						exponent = Some(match digits.parse() {
+							| Ok(exponent) => exponent,
+							// NOTE: un exposant négatif trop grand donne
+							// zéro, signalé plus bas.
+							| Err(_) if digits.starts_with('-') => i32::MIN,
+							| Err(_) => {
+								return Err(
+									FloatParseError::Overflow { span }.into()
+								);
+							}
+						});
+					}
+					state.switch(IntegerState::Suffix);
+				}
+
+				| IntegerState::Hexadecimal
+				| IntegerState::Binary
+				| IntegerState::Octal => {
+					let radix = prefix.map_or(10, NumericPrefix::radix);
+					self.consume_digits(&mut data, radix)?;
+					if data.is_empty() {
+						return Err(IntegerParseError::MissingDigits {
+							radix,
+							span: Span::new(start, self.location),
+						}
+						.into());
+					}
+					state.switch(IntegerState::Suffix);
+				}
+
+				| IntegerState::Suffix => {
+					let next = self.peek_codepoint().maybe_unit();
+					if self.profile.numeric_suffixes.is_empty()
+						|| !next.is_some_and(|unit| unit.is_ascii_alphabetic())
+					{
+						break;
+					}
+
+					let location = self.next_location();
+					let mut text = String::new();
+					self.consume_while(&mut text, |unit| {
+						unit.is_ascii_alphanumeric() || unit == '_'
+					});
+					let span = Span::new(location, self.location);
+
+					// Un nombre décimal entier peut avoir un suffixe flottant,
+					// un nombre flottant ne peut avoir qu'un suffixe flottant.
+					let is_float = data.contains('.') || exponent.is_some();
+					suffix = self
+						.profile
+						.numeric_suffixes
+						.iter()
+						.copied()
+						.filter(|ty| {
+							if ty.is_float() {
+								prefix.is_none()
+							} else {
+								!is_float
+							}
+						})
+						.find(|ty| ty.as_str() == text);
+					if suffix.is_none() {
+						return Err(
+							IntegerParseError::InvalidSuffix { span }.into()
+						);
+					}
+					break;
+				}
+
+				| IntegerState::IsNot => {
+					return Err(IntegerParseError::IsNot {
+						found: Self::as_char(self.peek_codepoint()),
+					}
+					.into());
+				}
+			}
+		}
+
+		let span = Span::new(start, self.location);
+
+		let is_float = data.contains('.')
+			|| exponent.is_some()
+			|| suffix.is_some_and(NumericSuffix::is_float);
+
+		if !is_float {
+			// NOTE: CSS Syntax §4.3.13 ne rejette aucun nombre : avec le
+			// profil CSS, une valeur trop grande est bornée à `u128::MAX`,
+			// et [TokenizerAlgorithms::consume_numeric_token] calcule sa
+			// valeur à partir de ses chiffres.
+			let value = match u128::from_str_radix(
+				&data,
+				prefix.map_or(10, NumericPrefix::radix),
+			) {
+				| Ok(value) => value,
+				| Err(_) if self.profile.numeric_dimensions => u128::MAX,
+				| Err(_) => {
+					return Err(IntegerParseError::Overflow { span }.into())
+				}
+			};
+			if let Some(suffix) = suffix.filter(|suffix| {
+				suffix.max_integer().is_some_and(|max| value > max)
+			}) {
+				return Err(
+					IntegerParseError::OutOfRange { suffix, span }.into()
+				);
+			}
+
+			let integer = match prefix {
+				| Some(NumericPrefix::Hexadecimal) => {
+					Integer::Hexadecimal { hex: data }
+				}
+				| Some(NumericPrefix::Binary) => Integer::Binary { bin: data },
+				| Some(NumericPrefix::Octal) => Integer::Octal { oct: data },
+				| None => Integer::Decimal { int: data },
+			};
+
+			return Ok(Literal::Integer(IntegerOutput {
+				integer,
+				suffix,
+				value,
+				span,
+			}));
+		}
+
+		let value: f64 = format!("{data}e{}", exponent.unwrap_or_default())
+			.parse()
+			.expect("nombre décimal");
+		// NOTE: un nombre suffixé par `f32` est vérifié dans les limites de
+		// `f32`, sa valeur reste un `f64`.
+		let rounded = match suffix {
+			| Some(NumericSuffix::F32) => f64::from(value as f32),
+			| _ => value,
+		};
+		if rounded.is_infinite() {
+			return Err(FloatParseError::Overflow { span }.into());
+		}
+
+		let float = FloatOutput {
+			mantissa: data,
+			exponent,
+			suffix,
+			value,
+			span,
+		};
+		// NOTE: un zéro écrit, quel que soit son exposant, est exact.
+		let is_zero = float
+			.mantissa
+			.chars()
+			.all(|ch| matches!(ch, '0' | '.' | '_'));
+		if !is_zero && rounded == 0.0 {
+			self.warn(FloatParseError::Underflow { span }, span);
+		} else if !is_zero && !float.is_lossless() {
+			self.warn(FloatParseError::PrecisionLoss { span }, span);
+		}
+
+		Ok(Literal::Float(float))
+	}
+
+	#[cfg(feature = "literal")]
+	fn consume_numeric_token(&mut self) -> Result<Literal, LiteralParseError> {
+		let start = self.next_location();
+		let sign = match self.peek_codepoint().maybe_unit() {
+			| Some('+') => Some(NumericSign::Plus),
+			| Some('-') => Some(NumericSign::Minus),
+			| _ => None,
+		};
+		if sign.is_some() {
+			self.consume_codepoint();
+		}
+
+		let (ty, magnitude) = match self.consume_numeric()? {
+			| Literal::Integer(IntegerOutput {
+				integer: Integer::Decimal { int },
+				..
+			}) => (NumberType::Integer, int.parse().expect("chiffres décimaux")),
+			| Literal::Integer(int) => (NumberType::Integer, int.value as f64),
+			| Literal::Float(float) => (NumberType::Number, float.value),
+			| literal => unreachable!("nombre attendu, {literal}"),
+		};
+
+		let number = NumberOutput {
+			sign,
+			ty,
+			value: match sign {
+				| Some(NumericSign::Minus) => -magnitude,
+				| _ => magnitude,
+			},
+			span: Span::new(start, self.location),
+		};
+
+		if self.peek_codepoint().maybe_unit() == Some('%') {
+			self.consume_codepoint();
+			return Ok(Literal::Percentage(PercentageOutput {
+				number,
+				span: Span::new(start, self.location),
+			}));
+		}
+
+		if self.next_would_start_css_ident() {
+			let unit = self.consume_css_ident();
+			return Ok(Literal::Dimension(DimensionOutput {
+				number,
+				unit,
+				span: Span::new(start, self.location),
+			}));
+		}
+
+		Ok(Literal::Number(number))
+	}
+
+	#[cfg(feature = "literal")]
+	fn consume_string(&mut self) -> Result<StringOutput, StringParseError> {
+		let mut state = StringState::default();
+		let mut output = StringOutput {
+			data: String::new(),
+			raw: String::new(),
+			span: Span::from(self.next_location()),
+		};
+
+		// Le début du texte source de la chaîne dans le texte consommé.
+		let mut offset = 0;
+
+		loop {
+			match state {
+				| StringState::Initial => match self.peek_codepoint() {
+					| codepoint
+						if self.profile.raw_strings
+							&& codepoint.maybe_unit() == Some('r') =>
+					{
+						self.consume_codepoint();
+						let mut hashes = 0;
+						while self.peek_codepoint() == CodePoint::NUMBER_SIGN {
+							self.consume_codepoint();
+							hashes += 1;
+						}
+						state.switch(StringState::RawStart { hashes });
+					}
+					| codepoint
+						if !self
+							.profile
+							.string_quotes
+							.contains(&Self::as_char(codepoint)) =>
+					{
+						return Err(StringParseError::IsNot {
+							found: Self::as_char(codepoint),
+						});
+					}
+					| CodePoint::QUOTATION_MARK
+						if self.profile.triple_quoted_strings
+							&& self.next_is(r#"""""#) =>
+					{
+						(0..3).for_each(|_| {
+							self.consume_codepoint();
+						});
+						offset = self.consumed.len();
+						state.switch(StringState::TripleQuoted);
+					}
+					| CodePoint::QUOTATION_MARK => {
+						self.consume_codepoint();
+						offset = self.consumed.len();
+						state.switch(StringState::DoubleQuoted);
+					}
+					| CodePoint::APOSTROPHE => {
+						self.consume_codepoint();
+						offset = self.consumed.len();
+						state.switch(StringState::SingleQuoted);
+					}
+					| codepoint => {
+						return Err(StringParseError::IsNot {
+							found: Self::as_char(codepoint),
+						});
+					}
+				},
+
+				| StringState::TripleQuoted => {
+					output.data = self.consume_triple_quoted(output.span)?;
+					let closing = self.consumed.len() - 3;
+					output.raw = self.consumed[offset..closing].to_owned();
+					output.span.end = self.location;
+					return Ok(output);
+				}
+
+				| StringState::RawStart { hashes } => {
+					match self.peek_codepoint() {
+						| CodePoint::QUOTATION_MARK => {
+							self.consume_codepoint();
+							offset = self.consumed.len();
+							state.switch(StringState::Raw { hashes });
+						}
+						| codepoint => {
+							return Err(StringParseError::IsNot {
+								found: Self::as_char(codepoint),
+							});
+						}
+					}
+				}
+
+				| StringState::Raw { hashes } => match self.peek_codepoint() {
+					| CodePoint::EOF => {
+						return Err(StringParseError::UnterminatedRaw {
+							hashes,
+							line: output.span.start.line,
+							column: output.span.start.column,
+						});
+					}
+					| CodePoint::QUOTATION_MARK
+						if self
+							.next_is(&format!("\"{}", "#".repeat(hashes))) =>
+					{
+						output.raw = self.consumed[offset..].to_owned();
+						(0..=hashes).for_each(|_| {
+							self.consume_codepoint();
+						});
+						output.span.end = self.location;
+						return Ok(output);
+					}
+					| _ => output
+						.data
+						.push(Self::as_char(self.consume_codepoint())),
+				},
+
+				| StringState::SingleQuoted | StringState::DoubleQuoted => {
+					let ending = match state {
+						| StringState::SingleQuoted => '\'',
+						| _ => '"',
+					};
+
+					match self.peek_codepoint() {
+						| CodePoint::EOF => {
+							return Err(StringParseError::Unterminated {
+								line: output.span.start.line,
+								column: output.span.start.column,
+							});
+						}
+						| CodePoint::Newline(_) => {
+							return Err(StringParseError::UnescapedNewline {
+								span: Span::new(
+									output.span.start,
+									self.location,
+								),
+							});
+						}
+						| codepoint if codepoint.maybe_unit() == Some(ending) =>
+						{
+							output.raw = self.consumed[offset..].to_owned();
+							self.consume_codepoint();
+							output.span.end = self.location;
+							return Ok(output);
+						}
+						| CodePoint::REVERSE_SOLIDUS
+							if self.profile.string_escapes =>
+						{
+							self.consume_escape(&mut output.data, false)?;
+						}
+						| CodePoint::REVERSE_SOLIDUS => {
+							output
+								.data
+								.push(Self::as_char(self.consume_codepoint()));
+							if let Some(escaped) =
+								self.peek_codepoint().maybe_unit()
+							{
+								self.consume_codepoint();
+								output.data.push(escaped);
+							}
+						}
+						| _ => output
+							.data
+							.push(Self::as_char(self.consume_codepoint())),
+					}
+				}
+			}
+		}
+	}
+
+	#[cfg(feature = "literal")]
+	fn consume_char(&mut self) -> Result<CharOutput, CharParseError> {
+		let start = self.next_location();
+		let quote = match self.peek_codepoint().maybe_unit() {
+			| Some(unit) if Some(unit) == self.profile.char_quote => unit,
+			| _ => {
+				return Err(CharParseError::IsNot {
+					found: Self::as_char(self.peek_codepoint()),
+				});
+			}
+		};
+
+		self.consume_codepoint();
+		let offset = self.consumed.len();
+		let mut data = String::new();
+
+		let unterminated = CharParseError::Unterminated {
+			line: start.line,
+			column: start.column,
+		};
+
+		loop {
+			match self.peek_codepoint() {
+				| CodePoint::EOF | CodePoint::Newline(_) => {
+					return Err(unterminated);
+				}
+				| codepoint if codepoint.maybe_unit() == Some(quote) => break,
+				| CodePoint::REVERSE_SOLIDUS if self.profile.string_escapes => {
+					self.consume_escape(&mut data, false)
+						.map_err(CharParseError::Escape)?;
+				}
+				| _ => data.push(Self::as_char(self.consume_codepoint())),
+			}
+		}
+
+		let raw = self.consumed[offset..].to_owned();
+		self.consume_codepoint();
+		let span = Span::new(start, self.location);
+
+		let mut chars = data.chars();
+		match (chars.next(), chars.next()) {
+			| (None, _) => Err(CharParseError::Empty { span }),
+			| (Some(value), None) => Ok(CharOutput { value, raw, span }),
+			| (Some(_), Some(_)) => {
+				Err(CharParseError::TooManyCharacters { span })
+			}
+		}
+	}
+
+	#[cfg(feature = "literal")]
+	fn consume_byte(&mut self) -> Result<ByteOutput, ByteParseError> {
+		let start = self.next_location();
+		let quote = self.profile.char_quote.unwrap_or('\'');
+		let (bytes, raw) = self.consume_bytes(quote)?;
+		let span = Span::new(start, self.location);
+
+		match bytes.as_slice() {
+			| [] => Err(ByteParseError::Empty { span }),
+			| [value] => Ok(ByteOutput {
+				value: *value,
+				raw,
+				span,
+			}),
+			| _ => Err(ByteParseError::TooManyCharacters { span }),
+		}
+	}
+
+	#[cfg(feature = "literal")]
+	fn consume_byte_string(
+		&mut self,
+	) -> Result<ByteStringOutput, ByteParseError> {
+		let start = self.next_location();
+		let (value, raw) = self.consume_bytes('"')?;
+
+		Ok(ByteStringOutput {
+			value,
+			raw,
+			span: Span::new(start, self.location),
+		})
+	}
+
+	#[cfg(feature = "literal")]
+	fn consume_template(&mut self) -> Result<TemplateOutput, StringParseError> {
+		let span_start = self.next_location();
+
+		let (head, start) = match self.peek_codepoint() {
+			| CodePoint::Unit(unit) if unit.as_char() == '`' => {
+				(true, span_start)
+			}
+			| CodePoint::RIGHT_CURLY_BRACKET => match self.modes.pop() {
+				| Some(LexerMode::TemplateInterpolation { start, .. }) => {
+					(false, start)
+				}
+				| None => {
+					return Err(StringParseError::IsNot { found: '}' });
+				}
+			},
+			| codepoint => {
+				return Err(StringParseError::IsNot {
+					found: Self::as_char(codepoint),
+				});
+			}
+		};
+
+		self.consume_codepoint();
+		let offset = self.consumed.len();
+		let mut data = String::new();
+
+		let (part, raw) = loop {
+			match self.peek_codepoint() {
+				| CodePoint::EOF => {
+					return Err(StringParseError::UnterminatedTemplate {
+						line: start.line,
+						column: start.column,
+					});
+				}
+				| CodePoint::Unit(unit) if unit.as_char() == '`' => {
+					let raw = self.consumed[offset..].to_owned();
+					self.consume_codepoint();
+					let part = match head {
+						| true => TemplatePart::NoSubstitution,
+						| false => TemplatePart::Tail,
+					};
+					break (part, raw);
+				}
+				| CodePoint::Unit(unit)
+					if unit.as_char() == '$' && self.next_is("${") =>
+				{
+					let raw = self.consumed[offset..].to_owned();
+					self.consume_codepoint();
+					self.consume_codepoint();
+					self.modes.push(LexerMode::TemplateInterpolation {
+						braces: 0,
+						start,
+					});
+					let part = match head {
+						| true => TemplatePart::Head,
+						| false => TemplatePart::Middle,
+					};
+					break (part, raw);
+				}
+				| CodePoint::REVERSE_SOLIDUS if self.profile.string_escapes => {
+					self.consume_escape(&mut data, false)?;
+				}
+				| _ => data.push(Self::as_char(self.consume_codepoint())),
+			}
+		};
+
+		Ok(TemplateOutput {
+			part,
+			data,
+			raw,
+			span: Span::new(span_start, self.location),
+		})
+	}
+
+	#[cfg(feature = "literal")]
+	fn consume_boolean(&mut self) -> Option<BoolOutput> {
+		if !self.profile.booleans {
+			return None;
+		}
+
+		let next = self.peek_n_codepoints(7);
+		let word: String = next
+			.iter()
+			.map_while(|codepoint| codepoint.maybe_unit())
+			.collect();
+
+		let (value, length) = if word.starts_with("true") {
+			(Bool::True, 4)
+		} else if word.starts_with("false") {
+			(Bool::False, 5)
+		} else {
+			return None;
+		};
+
+		// NOTE: en syntaxe CSS, un identifiant peut continuer par `-` ou par
+		// une séquence d'échappement : `true-color`, `true\2d x`.
+		#[cfg(feature = "identifier")]
+		let continues_ident = if self.profile.css_identifiers {
+			next[length].is_ident()
+				|| next[length] == CodePoint::REVERSE_SOLIDUS
+					&& !matches!(next[length + 1], CodePoint::Newline(_))
+		} else {
+			next[length].is_ident_after_start()
+		};
+		#[cfg(not(feature = "identifier"))]
+		let continues_ident = next[length].is_ident_after_start();
+
+		if continues_ident {
+			return None;
+		}
+
+		let start = self.next_location();
+		(0..length).for_each(|_| {
+			self.consume_codepoint();
+		});
+
+		Some(BoolOutput {
+			value,
+			span: Span::new(start, self.location),
+		})
+	}
+}
+
+impl<S, U> StreamIterator for Tokenizer<S>
+where
+	S: StreamIterator<Item = CodePoint<U>>,
+	U: CodePointInterface,
+{
+	type Error = LexicalError;
+	type Item = Token;
+
+	fn current(&self) -> Option<Self::Item> {
+		self.current_token.clone()
+	}
+
+	fn consume_next(&mut self) -> Result<Self::Item, Self::Error> {
+		let token = match self.lookahead.pop_front() {
+			| Some(entry) => self.consume_lookahead(entry),
+			| None => self.next_token(),
+		};
+
+		if let Ok(token) = &token {
+			self.current_token.replace(token.clone());
+		}
+
+		token
+	}
+
+	fn peek_next(&mut self) -> Result<Self::Item, Self::Error> {
+		self.peek_n_next(1).remove(0)
+	}
+
+	fn peek_n_next(
+		&mut self,
+		n: usize,
+	) -> Vec<Result<Self::Item, Self::Error>> {
+		while self.lookahead.len() < n {
+			let entry = self.next_lookahead();
+			self.lookahead.push_back(entry);
+		}
+		self.lookahead
+			.iter()
+			.take(n)
+			.map(|entry| entry.token.clone())
+			.collect()
+	}
+
+	fn reconsume_current(&mut self) {
+		if let Some(token) = self.current_token.clone() {
+			let state = match self.lookahead.front() {
+				| Some(entry) => entry.state.clone(),
+				| None => self.state(),
+			};
+			self.lookahead.push_front(Lookahead {
+				token: Ok(token),
+				text: String::new(),
+				codepoints: Vec::new(),
+				diagnostics: Vec::new(),
+				#[cfg(feature = "identifier")]
+				checked: Vec::new(),
+				state,
+			});
+		}
+	}
+}
+
+impl<S, U> TokenizerRecovery for Tokenizer<S>
+where
+	S: StreamIterator<Item = CodePoint<U>>,
+	U: CodePointInterface,
+{
+	type Token = Token;
+	type Unit = U;
+
+	/// L'emplacement de fin du dernier jeton consommé, avant les jetons
+	/// analysés à l'avance.
+	fn location(&self) -> Location {
+		match self.lookahead.front() {
+			| Some(entry) => entry.state.location,
+			| None => self.location,
+		}
+	}
+
+	/// Consomme le prochain jeton. Un jeton analysé à l'avance est consommé
+	/// avec son texte, puis l'analyseur revient juste après lui afin que la
+	/// resynchronisation reparte du bon point de code.
+	fn consume_token(&mut self) -> Option<Result<Self::Token, LexicalError>> {
+		let token = match self.lookahead.pop_front() {
+			| Some(entry) => {
+				let token = self.consume_lookahead(entry);
+				self.rewind_lookahead();
+				token
+			}
+			| None => self.next_token(),
+		};
+
+		if let Ok(token) = &token {
+			self.current_token.replace(token.clone());
+		}
+
+		match token {
+			| Ok(Token::EOF(_)) => None,
+			| token => Some(token),
+		}
+	}
+
+	fn take_consumed_text(&mut self) -> String {
+		core::mem::take(&mut self.consumed)
+	}
+
+	fn peek_codepoint(&mut self) -> CodePoint<Self::Unit> {
+		Tokenizer::peek_codepoint(self)
+	}
+
+	fn peek_n_codepoints(&mut self, n: usize) -> Vec<CodePoint<Self::Unit>> {
+		Tokenizer::peek_n_codepoints(self, n)
+	}
+
+	fn consume_codepoint(&mut self) -> CodePoint<Self::Unit> {
+		Tokenizer::consume_codepoint(self)
+	}
+
+	fn take_diagnostics(&mut self) -> Vec<Diagnostic> {
+		Tokenizer::take_diagnostics(self)
+	}
+}
+
+// ---- //
+// Test //
+// ---- //
+
+#[cfg(test)]
+mod tests {
+	use core::str::Chars;
+
+	use locale::LocalizedMessage;
+	use stream::prelude::{InputStream, StreamIteratorItem, TokenStream};
+
+	use super::*;
+	use crate::{
+		keyword::{css, mscss, Keyword, KeywordEntry},
+		literal::LiteralParseError,
+		token::TokenKind,
+	};
+
+	fn tokenizer(source: &str) -> Tokenizer<InputStream<Chars<'_>, char>> {
+		Tokenizer::new(InputStream::new(source.chars()))
+	}
+
+	/// Les noms des jetons analysés jusqu'à EOF, exclu, ou la première
+	/// erreur d'analyse.
+	fn token_names(
+		mut t: Tokenizer<InputStream<Chars<'_>, char>>,
+	) -> Result<Vec<String>, LexicalError> {
+		let mut names = Vec::new();
+		loop {
+			match StreamIterator::consume_next(&mut t)? {
+				| token if token.is_eof() => return Ok(names),
+				| token => names.push(token.to_string()),
+			}
+		}
+	}
+
+	#[test]
+	fn test_consume_comments() {
+		let mut t = tokenizer("// line comment\n/* block * comment **/");
+
+		let line = t.consume_comments().unwrap();
+		assert_eq!(line.ty, CommentType::Line);
+		assert_eq!(line.data, " line comment");
+		assert_eq!((line.span.start.line, line.span.start.column), (1, 1));
+
+		let block = t.consume_comments().unwrap();
+		assert_eq!(block.ty, CommentType::Block);
+		assert_eq!(block.data, " block * comment *");
+		assert_eq!((block.span.start.line, block.span.start.column), (2, 1));
+
+		assert_eq!(
+			tokenizer("/ not a comment").consume_comments(),
+			Err(CommentParseError::IsNot)
+		);
+
+		let mut t = tokenizer("\n  /* unterminated *");
+		t.consume_codepoint();
+		t.consume_codepoint();
+		t.consume_codepoint();
+		assert_eq!(
+			t.consume_comments(),
+			Err(CommentParseError::Unterminated { line: 2, column: 3 })
+		);
+	}
+
+	#[test]
+	fn test_consume_doc_comments() {
+		let comment = |source: &str| {
+			let output = tokenizer(source).consume_comments().unwrap();
+			(output.ty, output.data)
+		};
+
+		assert_eq!(
+			comment("/// Documentation externe.\n"),
+			(CommentType::OuterDocLine, "Documentation externe.".into())
+		);
+		assert_eq!(
+			comment("//! Documentation interne."),
+			(CommentType::InnerDocLine, "Documentation interne.".into())
+		);
+		assert_eq!(
+			comment("/**\n * Première ligne.\n *\n * Deuxième ligne.\n */"),
+			(
+				CommentType::OuterDocBlock,
+				"Première ligne.\n\nDeuxième ligne.".into()
+			)
+		);
+		assert_eq!(
+			comment("/*! Module. */"),
+			(CommentType::InnerDocBlock, "Module.".into())
+		);
+		assert_eq!(comment("//// Ligne.").0, CommentType::Line);
+		assert_eq!(comment("/*** Bloc. */").0, CommentType::Block);
+		assert_eq!(comment("/**/"), (CommentType::Block, String::new()));
+
+		let mut t =
+			tokenizer("/** Bloc. */").define_profile(LexerProfile::css());
+		let output = t.consume_comments().unwrap();
+		assert_eq!(
+			(output.ty, output.data),
+			(CommentType::Block, "* Bloc. ".into())
+		);
+	}
+
+	#[test]
+	fn test_consume_html_comments() {
+		let comment = |source: &str| {
+			let mut t = tokenizer(source).define_profile(LexerProfile::html());
+			let output = t.consume_comments().unwrap();
+			let warnings: Vec<_> = t
+				.take_diagnostics()
+				.into_iter()
+				.map(|diagnostic| {
+					assert_eq!(diagnostic.severity, Severity::Warning);
+					let LexicalError::Comment(error) = diagnostic.error else {
+						panic!("une erreur de commentaire est attendue");
+					};
+					(error, diagnostic.span.start.column)
+				})
+				.collect();
+			(output.data, warnings)
+		};
+
+		assert_eq!(
+			comment("<!-- a - b -- c -->"),
+			(" a - b -- c ".into(), vec![])
+		);
+		assert_eq!(comment("<!-- <! <!- -->"), (" <! <!- ".into(), vec![]));
+		assert_eq!(
+			comment("<!-->"),
+			(
+				String::new(),
+				vec![(CommentParseError::AbruptClosingOfEmptyComment, 5)]
+			)
+		);
+		assert_eq!(
+			comment("<!--->"),
+			(
+				String::new(),
+				vec![(CommentParseError::AbruptClosingOfEmptyComment, 6)]
+			)
+		);
+		assert_eq!(
+			comment("<!-- a <!-- b -->"),
+			(
+				" a <!-- b ".into(),
+				vec![(CommentParseError::NestedComment, 8)]
+			)
+		);
+		assert_eq!(
+			comment("<!-- a --!>"),
+			(
+				" a ".into(),
+				vec![(CommentParseError::IncorrectlyClosedComment, 8)]
+			)
+		);
+		assert_eq!(comment("<!-- a --!- b -->"), (" a --!- b ".into(), vec![]));
+		assert_eq!(
+			comment("<!-- a"),
+			(" a".into(), vec![(CommentParseError::EofInComment, 7)])
+		);
+		assert_eq!(
+			comment("<!--\0-->"),
+			(
+				"\u{FFFD}".into(),
+				vec![(CommentParseError::UnexpectedNullCharacter, 5)]
+			)
+		);
+
+		let recovered = tokenizer("<!-- a")
+			.define_profile(LexerProfile::html())
+			.consume_tokens_with_recovery();
+		assert_eq!(recovered.tokens.len(), 1);
+		assert!(recovered.tokens[0].is_ok());
+		assert_eq!(recovered.diagnostics.len(), 1);
+		assert_eq!(recovered.diagnostics[0].severity, Severity::Warning);
+	}
+
+	#[test]
+	fn test_consume_nested_comments() {
+		let source = "/* outer /* inner */ still comment */ code";
+
+		let mut t = tokenizer(source);
+		let comment = t.consume_comments().unwrap();
+		assert_eq!(comment.data, " outer /* inner */ still comment ");
+		assert_eq!(comment.span.end.column, 37);
+		assert_eq!(t.peek_codepoint(), CodePoint::Whitespace(' '));
+
+		let mut t = tokenizer(source).define_profile(LexerProfile::css());
+		assert_eq!(t.consume_comments().unwrap().data, " outer /* inner ");
+		assert_eq!(t.peek_codepoint(), CodePoint::Whitespace(' '));
+
+		let mut t = tokenizer("a\n /* outer /* inner */ /* x */");
+		t.consume_codepoint();
+		t.consume_codepoint();
+		t.consume_codepoint();
+		assert_eq!(
+			t.consume_comments(),
+			Err(CommentParseError::Unterminated { line: 2, column: 2 })
+		);
+	}
+
+	#[test]
+	fn test_consume_ident_sequence() {
+		let mut t = tokenizer("hello_world2 rest");
+		let ident = t.consume_ident_sequence().unwrap();
+		assert_eq!(ident.identifier, "hello_world2");
+		assert_eq!(t.peek_codepoint(), CodePoint::Whitespace(' '));
+
+		assert_eq!(
+			tokenizer("fn main").consume_ident_sequence(),
+			Err(IdentifierParseError::IsKeyword {
+				found: Keyword::Function.id(),
+				spelling: "fn".into(),
+			})
+		);
+		assert_eq!(
+			tokenizer("1abc").consume_ident_sequence(),
+			Err(IdentifierParseError::IsNot { found: '1' })
+		);
+	}
+
+	#[test]
+	fn test_contextual_keywords() {
+		let ident = tokenizer("from").consume_ident_sequence().unwrap();
+		assert_eq!(ident.identifier, "from");
+		assert_eq!(ident.keyword, Some(Keyword::From.id()));
+
+		let ident = tokenizer("main").consume_ident_sequence().unwrap();
+		assert_eq!(ident.keyword, None);
+
+		// NOTE: MSCSS a ses propres mots-clés, tous contextuels.
+		let mut t =
+			tokenizer("fn mixin from").define_profile(LexerProfile::mscss());
+		let ident = t.consume_ident_sequence().unwrap();
+		assert_eq!(ident.identifier, "fn");
+		assert_eq!(ident.keyword, None);
+		t.consume_codepoint();
+		let ident = t.consume_ident_sequence().unwrap();
+		assert_eq!(ident.keyword, Some(mscss::Keyword::Mixin.id()));
+		t.consume_codepoint();
+		let ident = t.consume_ident_sequence().unwrap();
+		assert_eq!(
+			ident.keyword.and_then(mscss::Keyword::from_id),
+			Some(mscss::Keyword::From)
+		);
+
+		let mut table = KeywordTable::new();
+		table.insert("def", KeywordEntry::reserved(Keyword::Function.id()));
+		table.insert("fn", KeywordEntry::contextual(Keyword::Function.id()));
+		let mut t = tokenizer("def fn").define_keywords(table);
+		assert_eq!(
+			t.consume_ident_sequence(),
+			Err(IdentifierParseError::IsKeyword {
+				found: Keyword::Function.id(),
+				spelling: "def".into(),
+			})
+		);
+		t.consume_codepoint();
+		let ident = t.consume_ident_sequence().unwrap();
+		assert_eq!(ident.keyword, Some(Keyword::Function.id()));
+
+		let mut t = tokenizer("func main");
+		let Ok(Token::Keyword(keyword)) = t.next_token() else {
+			panic!("mot-clé attendu");
+		};
+		assert_eq!(keyword.keyword, Keyword::Function.id());
+		assert_eq!(keyword.spelling, "func");
+		assert_eq!(keyword.keyword.canonical(), "fn");
+	}
+
+	#[test]
+	fn test_ascii_case_insensitive() {
+		let mut t = tokenizer("Color").define_profile(LexerProfile::css());
+		let ident = t.consume_ident_sequence().unwrap();
+		assert_eq!(ident.identifier, "Color");
+		assert_eq!(ident.normalized(), "color");
+		assert!(ident.is("COLOR"));
+
+		let ident = tokenizer("Color").consume_ident_sequence().unwrap();
+		assert_eq!(ident.normalized, None);
+		assert!(ident.is("Color") && !ident.is("color"));
+
+		// NOTE: seules les lettres ASCII sont converties.
+		let mut t = tokenizer("Élan").define_profile(LexerProfile::html());
+		assert_eq!(t.consume_ident_sequence().unwrap().normalized(), "Élan");
+
+		let mut t = tokenizer("MEDIA").define_profile(LexerProfile::css());
+		let ident = t.consume_ident_sequence().unwrap();
+		assert_eq!(ident.identifier, "MEDIA");
+		assert_eq!(ident.keyword, Some(css::Keyword::Media.id()));
+
+		let mut table = KeywordTable::new();
+		table.insert_aliases(KeywordEntry::reserved(css::Keyword::Import.id()));
+		table.insert(
+			"Supports",
+			KeywordEntry::contextual(css::Keyword::Supports.id()),
+		);
+		let mut t = tokenizer("IMPORT SUPPORTS")
+			.define_profile(LexerProfile::css())
+			.define_keywords(table);
+		let Ok(Token::Keyword(keyword)) = t.next_token() else {
+			panic!("mot-clé attendu");
+		};
+		assert_eq!(keyword.keyword, css::Keyword::Import.id());
+		assert_eq!(keyword.spelling, "IMPORT");
+		t.consume_codepoint();
+		let ident = t.consume_ident_sequence().unwrap();
+		assert_eq!(ident.identifier, "SUPPORTS");
+		assert_eq!(ident.keyword, Some(css::Keyword::Supports.id()));
+	}
+
+	#[test]
+	fn test_unicode_identifiers() {
+		let ident = tokenizer("ünïcødé_1").consume_ident_sequence().unwrap();
+		assert_eq!(ident.identifier, "ünïcødé_1");
+
+		let ident = tokenizer("变量 ").consume_ident_sequence().unwrap();
+		assert_eq!(ident.identifier, "变量");
+
+		// NOTE: ni un symbole, ni une flèche, ni un U+200D ZERO WIDTH JOINER
+		// ne peuvent commencer un identifiant.
+		for source in ["€uro", "→", "\u{200D}a"] {
+			assert!(matches!(
+				tokenizer(source).consume_ident_sequence(),
+				Err(IdentifierParseError::IsNot { .. })
+			));
+		}
+
+		let ident = tokenizer("a€").consume_ident_sequence().unwrap();
+		assert_eq!(ident.identifier, "a");
+
+		// NOTE: forme NFD : `e` suivi de U+0301 COMBINING ACUTE ACCENT.
+		let decomposed = tokenizer("cafe\u{301}").consume_ident_sequence();
+		let composed = tokenizer("caf\u{E9}").consume_ident_sequence();
+		assert_eq!(
+			decomposed.unwrap().identifier,
+			composed.unwrap().identifier
+		);
+
+		// NOTE: U+212A KELVIN SIGN est canoniquement équivalent à `K`.
+		let mut t =
+			tokenizer("\u{212A}EYFRAMES").define_profile(LexerProfile::css());
+		let ident = t.consume_ident_sequence().unwrap();
+		assert_eq!(ident.keyword, Some(css::Keyword::Keyframes.id()));
+	}
+
+	#[test]
+	fn test_confusable_identifiers() {
+		let ident = tokenizer("paypal").consume_ident_sequence().unwrap();
+		assert_eq!(ident.skeleton, None);
+
+		let mut t = tokenizer("paypal раураl pаypal burn bum раураl pаypal")
+			.define_confusable_detection(ConfusableDetector::new());
+		let idents = (0..7)
+			.map(|_| {
+				let ident = t.consume_ident_sequence().unwrap();
+				t.consume_codepoint();
+				ident
+			})
+			.collect::<Vec<_>>();
+		assert_eq!(idents[1].skeleton.as_deref(), Some("paypal"));
+
+		let codes = t
+			.take_diagnostics()
+			.iter()
+			.map(|diagnostic| (diagnostic.error.code(), diagnostic.span))
+			.collect::<Vec<_>>();
+		assert_eq!(
+			codes,
+			[
+				("identifier-mixed-script", idents[1].span),
+				("identifier-confusable", idents[1].span),
+				("identifier-mixed-script", idents[2].span),
+				("identifier-confusable", idents[2].span),
+			]
+		);
+
+		// NOTE: la détection est transmise à l'analyseur d'un autre fichier,
+		// les identifiants déjà signalés ne le sont plus.
+		let detector = t.take_confusable_detection().unwrap();
+		let mut t =
+			tokenizer("раураl рaypal").define_confusable_detection(detector);
+		t.consume_ident_sequence().unwrap();
+		assert!(t.take_diagnostics().is_empty());
+		t.consume_codepoint();
+		t.consume_ident_sequence().unwrap();
+		let diagnostics = t.take_diagnostics();
+		assert_eq!(diagnostics.len(), 2);
+		assert!(diagnostics[1].error.message().contains("paypal"));
+
+		// NOTE: un identifiant analysé à l'avance, puis analysé à nouveau
+		// après une récupération, n'est signalé qu'une seule fois.
+		let mut t = tokenizer("\"a\nраураl")
+			.define_confusable_detection(ConfusableDetector::new());
+		t.peek_n_next(3);
+		let codes = t
+			.consume_tokens_with_recovery()
+			.diagnostics
+			.iter()
+			.map(|diagnostic| diagnostic.error.code())
+			.collect::<Vec<_>>();
+		assert_eq!(
+			codes,
+			["string-unescaped-newline", "identifier-mixed-script"]
+		);
+	}
+
+	/// Consomme un nombre entier, qui ne doit pas être un nombre à virgule
+	/// flottante.
+	fn integer(source: &str) -> Result<IntegerOutput, IntegerParseError> {
+		tokenizer(source)
+			.consume_numeric()
+			.map(|literal| match literal {
+				| Literal::Integer(int) => int,
+				| literal => {
+					panic!("{source}: nombre entier attendu, {literal}")
+				}
+			})
+			.map_err(|error| match error {
+				| LiteralParseError::Integer(error) => error,
+				| error => {
+					panic!("{source}: erreur d'entier attendue, {error}")
+				}
+			})
+	}
+
+	/// Consomme un nombre à virgule flottante.
+	fn float(source: &str) -> Result<FloatOutput, LiteralParseError> {
+		tokenizer(source)
+			.consume_numeric()
+			.map(|literal| match literal {
+				| Literal::Float(float) => float,
+				| literal => {
+					panic!("{source}: nombre flottant attendu, {literal}")
+				}
+			})
+	}
+
+	#[test]
+	fn test_consume_numeric() {
+		let int = |source: &str| integer(source).map(|output| output.integer);
+
+		assert_eq!(int("71;"), Ok(Integer::Decimal { int: "71".into() }));
+		assert_eq!(int("0"), Ok(Integer::Decimal { int: "0".into() }));
+		assert_eq!(int("0x2A"), Ok(Integer::Hexadecimal { hex: "2A".into() }));
+		assert_eq!(
+			int("0b10000000000"),
+			Ok(Integer::Binary {
+				bin: "10000000000".into()
+			})
+		);
+		assert_eq!(int("0o2000"), Ok(Integer::Octal { oct: "2000".into() }));
+		assert_eq!(int("abc"), Err(IntegerParseError::IsNot { found: 'a' }));
+
+		let mantissa =
+			|source: &str| float(source).map(|output| output.mantissa);
+		assert_eq!(mantissa("10.0"), Ok("10.0".into()));
+		assert_eq!(mantissa(".463"), Ok(".463".into()));
+		assert_eq!(mantissa("1."), Ok("1.".into()));
+		assert_eq!(mantissa("1.;"), Ok("1.".into()));
+	}
+
+	#[test]
+	fn test_consume_numeric_trailing_dot() {
+		let names = |source: &str, profile: LexerProfile| {
+			token_names(tokenizer(source).define_profile(profile)).unwrap()
+		};
+		let ms = LexerProfile::mikuscript;
+
+		assert_eq!(
+			names("1..2", ms()),
+			["numeric-decimal", "range", "numeric-decimal"]
+		);
+		assert_eq!(
+			names("1..=2", ms()),
+			["numeric-decimal", "range-inclusive", "numeric-decimal"]
+		);
+		assert_eq!(
+			names("1.max", ms()),
+			["numeric-decimal", "dot", "identifier-max"]
+		);
+		assert_eq!(
+			names("1. .5", ms()),
+			["numeric-float", "whitespace", "numeric-float"]
+		);
+		assert_eq!(names("1.", LexerProfile::css()), ["number", "dot"]);
+	}
+
+	#[test]
+	fn test_consume_numeric_token() {
+		let token = |source: &str| {
+			tokenizer(source)
+				.define_profile(LexerProfile::css())
+				.consume_numeric_token()
+				.unwrap()
+		};
+		let number = |literal: Literal| match literal {
+			| Literal::Number(number) => (number.sign, number.ty, number.value),
+			| Literal::Percentage(PercentageOutput { number, .. })
+			| Literal::Dimension(DimensionOutput { number, .. }) => {
+				(number.sign, number.ty, number.value)
+			}
+			| literal => panic!("nombre CSS attendu, {literal}"),
+		};
+		let unit = |literal: Literal| match literal {
+			| Literal::Dimension(dimension) => dimension.unit,
+			| literal => panic!("dimension attendue, {literal}"),
+		};
+
+		assert_eq!(number(token("10")), (None, NumberType::Integer, 10.0));
+		assert_eq!(
+			number(token("+.5")),
+			(Some(NumericSign::Plus), NumberType::Number, 0.5)
+		);
+		assert_eq!(
+			number(token("-3e2deg")),
+			(Some(NumericSign::Minus), NumberType::Number, -300.0)
+		);
+		assert_eq!(unit(token("-3e2deg")), "deg");
+		assert_eq!(unit(token("10px")), "px");
+		assert_eq!(unit(token("1.5em")), "em");
+		assert_eq!(unit(token("1e3em")), "em");
+		assert_eq!(unit(token("1em")), "em");
+		assert_eq!(unit(token("2-x")), "-x");
+		assert_eq!(unit(token(r"10\70 x")), "px");
+		assert_eq!(unit(token(r"10\0")), "\u{FFFD}");
+		assert_eq!(unit(token(r"10\%")), "%");
+		assert!(matches!(
+			token("50%"),
+			Literal::Percentage(PercentageOutput { number, .. })
+				if number.ty == NumberType::Integer && number.value == 50.0
+		));
+		assert_eq!(token("-12.5%").to_string(), "percentage");
+
+		// NOTE: un entier trop grand pour `u128` n'est pas rejeté, il garde
+		// le type `integer`.
+		let huge = token(&format!("{}px", "9".repeat(41)));
+		assert_eq!(number(huge.clone()), (None, NumberType::Integer, 1e41));
+		assert_eq!(unit(huge), "px");
+		assert!(matches!(
+			tokenizer(&"9".repeat(41)).consume_numeric(),
+			Err(LiteralParseError::Integer(
+				IntegerParseError::Overflow { .. }
+			))
+		));
+
+		let names = |source: &str| {
+			token_names(tokenizer(source).define_profile(LexerProfile::mscss()))
+				.unwrap()
+		};
+
+		assert_eq!(
+			names("margin: -1px 50% 0 +2em;"),
+			[
+				"identifier-margin",
+				"colon",
+				"whitespace",
+				"dimension-px",
+				"whitespace",
+				"percentage",
+				"whitespace",
+				"number",
+				"whitespace",
+				"dimension-em",
+				"semicolon",
+			]
+		);
+		assert_eq!(names("1 - 2")[2], "subtraction");
+		assert_eq!(names("-x"), ["identifier--x"]);
+		assert_eq!(names("- x")[0], "subtraction");
+		assert_eq!(
+			names("display: -webkit-box"),
+			[
+				"identifier-display",
+				"colon",
+				"whitespace",
+				"identifier--webkit-box"
+			]
+		);
+		assert_eq!(
+			names("--custom: 1"),
+			["identifier---custom", "colon", "whitespace", "number"]
+		);
+		assert_eq!(names(r"\70 x"), ["identifier-px"]);
+		assert_eq!(names(r"a\-b"), ["identifier-a-b"]);
+	}
+
+	#[test]
+	fn test_numeric_values() {
+		let int = |source: &str| integer(source).map(|output| output.value);
+
+		assert_eq!(int("71"), Ok(71));
+		assert_eq!(int("0"), Ok(0));
+		assert_eq!(int("0x2A"), Ok(42));
+		assert_eq!(int("0b10000000000"), Ok(1024));
+		assert_eq!(int("0o2000"), Ok(1024));
+		assert_eq!(int(&u128::MAX.to_string()), Ok(u128::MAX));
+		assert_eq!(float("10.0").map(|f| f.value), Ok(10.0));
+		assert_eq!(float(".463").map(|f| f.value), Ok(0.463));
+		assert_eq!(float("1.").map(|f| f.value), Ok(1.0));
+
+		assert!(matches!(
+			int("340282366920938463463374607431768211456"),
+			Err(IntegerParseError::Overflow { .. })
+		));
+		assert!(matches!(
+			int(&format!("0x1{}", "0".repeat(32))),
+			Err(IntegerParseError::Overflow { .. })
+		));
+		assert!(matches!(
+			float(&format!("1{}.0", "0".repeat(400))),
+			Err(LiteralParseError::Float(FloatParseError::Overflow { .. }))
+		));
+
+		let warnings = |source: &str| {
+			let mut t = tokenizer(source);
+			t.consume_numeric().unwrap();
+			t.take_diagnostics()
+				.into_iter()
+				.map(|diagnostic| (diagnostic.severity, diagnostic.error))
+				.collect::<Vec<_>>()
+		};
+
+		assert!(warnings("0.1").is_empty());
+		assert!(warnings("0.000001250").is_empty());
+		assert!(warnings("123456789012345678901234567890").is_empty());
+		assert!(matches!(
+			warnings("0.30000000000000000001").as_slice(),
+			[(
+				Severity::Warning,
+				LexicalError::Literal(LiteralParseError::Float(
+					FloatParseError::PrecisionLoss { .. }
+				))
+			)]
+		));
+		assert!(matches!(
+			warnings("1e-400").as_slice(),
+			[(
+				Severity::Warning,
+				LexicalError::Literal(LiteralParseError::Float(
+					FloatParseError::Underflow { .. }
+				))
+			)]
+		));
+		assert!(matches!(
+			warnings("1e-99999999999").as_slice(),
+			[(
+				Severity::Warning,
+				LexicalError::Literal(LiteralParseError::Float(
+					FloatParseError::Underflow { .. }
+				))
+			)]
+		));
+		assert!(warnings("0.0e-400").is_empty());
+
+		let mut t = tokenizer("16777217f32 16777216f32 0.1f32 16777217f64");
+		let precision_losses = (0..4)
+			.map(|_| {
+				t.consume_numeric().unwrap();
+				t.consume_codepoint();
+				t.take_diagnostics().len()
+			})
+			.collect::<Vec<_>>();
+		assert_eq!(precision_losses, [1, 0, 0, 0]);
+		let mut t = tokenizer("16777217f32");
+		t.consume_numeric().unwrap();
+		assert!(matches!(
+			t.take_diagnostics().as_slice(),
+			[Diagnostic {
+				severity: Severity::Warning,
+				error: LexicalError::Literal(LiteralParseError::Float(
+					FloatParseError::PrecisionLoss { .. }
+				)),
+				..
+			}]
+		));
+	}
+
+	#[test]
+	fn test_numeric_errors() {
+		let error = |source: &str| {
+			let LiteralParseError::Integer(error) =
+				tokenizer(source).consume_numeric().unwrap_err()
+			else {
+				panic!("{source}: erreur d'entier attendue");
+			};
+			let span = match error {
+				| IntegerParseError::InvalidDigit { span, .. }
+				| IntegerParseError::MissingDigits { span, .. }
+				| IntegerParseError::MisplacedSeparator { span }
+				| IntegerParseError::LeadingZero { span }
+				| IntegerParseError::MultipleDecimalPoints { span } => span,
+				| error => panic!("{source}: {error:?}"),
+			};
+			(error.code(), span.start.column, span.end.column)
+		};
+
+		assert_eq!(error("0b102"), ("integer-invalid-digit", 5, 5));
+		assert_eq!(error("0o9"), ("integer-invalid-digit", 3, 3));
+		assert_eq!(error("0x"), ("integer-missing-digits", 1, 2));
+		assert_eq!(error("0b;"), ("integer-missing-digits", 1, 2));
+		assert_eq!(error("1__0"), ("integer-misplaced-separator", 3, 3));
+		assert_eq!(error("08"), ("integer-leading-zero", 1, 1));
+		assert_eq!(error("0_1"), ("integer-leading-zero", 1, 1));
+		assert_eq!(error("1.2.3"), ("integer-multiple-decimal-points", 4, 4));
+
+		assert!(matches!(
+			integer("0b102"),
+			Err(IntegerParseError::InvalidDigit {
+				found: '2',
+				radix: 2,
+				..
+			})
+		));
+
+		// `0` seul, suivi d'une virgule, d'un suffixe ou d'un intervalle.
+		assert!(integer("0").is_ok());
+		assert!(integer("0u8").is_ok());
+		assert!(integer("0..2").is_ok());
+		assert!(float("0.5").is_ok());
+		assert!(float("0e3").is_ok());
+		assert!(float("1.2..3").is_ok());
+
+		// Les profils CSS autorisent les zéros de début, et `1.2.3` est une
+		// suite de deux nombres.
+		let mut t = tokenizer("007 1.2.3").define_profile(LexerProfile::css());
+		assert!(matches!(
+			t.consume_numeric(),
+			Ok(Literal::Integer(IntegerOutput { value: 7, .. }))
+		));
+		t.consume_codepoint();
+		assert!(matches!(t.consume_numeric(), Ok(Literal::Float(_))));
+		assert!(matches!(t.consume_numeric(), Ok(Literal::Float(_))));
+	}
+
+	#[test]
+	fn test_numeric_separators_exponents_suffixes() {
+		let int = |source: &str| {
+			integer(source)
+				.map(|output| (output.integer, output.suffix, output.value))
+		};
+		let parts = |source: &str| {
+			float(source).map(|output| {
+				(
+					output.mantissa,
+					output.exponent,
+					output.suffix,
+					output.value,
+				)
+			})
+		};
+		let decimal = |int: &str| Integer::Decimal { int: int.into() };
+
+		assert_eq!(int("1_000_000"), Ok((decimal("1000000"), None, 1_000_000)));
+		assert_eq!(
+			int("0xFF_FF"),
+			Ok((Integer::Hexadecimal { hex: "FFFF".into() }, None, 0xFFFF))
+		);
+		assert_eq!(
+			int("10u8"),
+			Ok((decimal("10"), Some(NumericSuffix::U8), 10))
+		);
+		assert_eq!(
+			int("3i64"),
+			Ok((decimal("3"), Some(NumericSuffix::I64), 3))
+		);
+		assert_eq!(
+			int("0b1010u8").map(|(_, suffix, value)| (suffix, value)),
+			Ok((Some(NumericSuffix::U8), 10))
+		);
+
+		assert_eq!(parts("1e-9"), Ok(("1".into(), Some(-9), None, 1e-9)));
+		assert_eq!(parts("2.5E+3"), Ok(("2.5".into(), Some(3), None, 2500.0)));
+		assert_eq!(
+			parts("2.0f32"),
+			Ok(("2.0".into(), None, Some(NumericSuffix::F32), 2.0))
+		);
+		assert_eq!(
+			parts("3f64"),
+			Ok(("3".into(), None, Some(NumericSuffix::F64), 3.0))
+		);
+
+		let error_column =
+			|source: &str| match tokenizer(source).consume_numeric() {
+				| Err(LiteralParseError::Integer(
+					IntegerParseError::MisplacedSeparator { span }
+					| IntegerParseError::InvalidSuffix { span },
+				)) => (span.start.column, span.end.column),
+				| result => panic!("{source}: {result:?}"),
+			};
+
+		assert_eq!(error_column("1__0"), (3, 3));
+		assert_eq!(error_column("1_"), (2, 2));
+		assert_eq!(error_column("0x_1"), (3, 3));
+		assert_eq!(error_column("1_.5"), (2, 2));
+		assert_eq!(error_column("1e_5"), (2, 4));
+		assert_eq!(error_column("10u7"), (3, 4));
+		assert_eq!(error_column("2.0u8"), (4, 5));
+		assert_eq!(error_column("1e3i32"), (4, 6));
+		assert_eq!(error_column("0b1f32"), (4, 6));
+
+		assert!(matches!(
+			float("1e99999999999"),
+			Err(LiteralParseError::Float(FloatParseError::Overflow { .. }))
+		));
+		assert!(matches!(
+			float("1e400"),
+			Err(LiteralParseError::Float(FloatParseError::Overflow { .. }))
+		));
+		assert!(matches!(
+			float("1e39f32"),
+			Err(LiteralParseError::Float(FloatParseError::Overflow { .. }))
+		));
+		assert!(float("1e38f32").is_ok());
+
+		// Les nombres suffixés sont vérifiés dans les limites de leur type.
+		let out_of_range = |source: &str| match integer(source) {
+			| Err(IntegerParseError::OutOfRange { suffix, .. }) => {
+				Some(suffix.as_str())
+			}
+			| Ok(_) => None,
+			| Err(error) => panic!("{source}: {error:?}"),
+		};
+		assert_eq!(out_of_range("18446744073709551616u8"), Some("u8"));
+		assert_eq!(out_of_range("256u8"), Some("u8"));
+		assert_eq!(out_of_range("0xFFi8"), Some("i8"));
+		assert_eq!(out_of_range("129i8"), Some("i8"));
+		assert_eq!(out_of_range("18446744073709551616u64"), Some("u64"));
+		assert_eq!(out_of_range("255u8"), None);
+		assert_eq!(out_of_range("128i8"), None);
+		assert_eq!(out_of_range("0x7Fi8"), None);
+		assert_eq!(out_of_range(&format!("{}u128", u128::MAX)), None);
+
+		// Les profils CSS n'ont ni séparateurs, ni suffixes.
+		let mut t =
+			tokenizer("1_000 10px 1e3").define_profile(LexerProfile::css());
+		let Ok(Literal::Integer(output)) = t.consume_numeric() else {
+			panic!("nombre entier attendu");
+		};
+		assert_eq!(output.integer, decimal("1"));
+		assert_eq!(t.consume_ident_sequence().unwrap().identifier, "_000");
+		t.consume_codepoint();
+		let Ok(Literal::Integer(output)) = t.consume_numeric() else {
+			panic!("nombre entier attendu");
+		};
+		assert_eq!(output.suffix, None);
+		assert_eq!(t.consume_ident_sequence().unwrap().identifier, "px");
+		t.consume_codepoint();
+		let Ok(Literal::Float(output)) = t.consume_numeric() else {
+			panic!("nombre flottant attendu");
+		};
+		assert_eq!(output.exponent, Some(3));
+	}
+
+	#[test]
+	fn test_consume_string() {
+		let mut t = tokenizer(r#""hello \"world\"" 'single'"#);
+		let string = t.consume_string().unwrap();
+		assert_eq!(string.data, r#"hello "world""#);
+		assert_eq!(string.raw, r#"hello \"world\""#);
+		assert_eq!((string.span.start.line, string.span.start.column), (1, 1));
+
+		t.consume_codepoint();
+		assert_eq!(
+			t.consume_string(),
+			Err(StringParseError::IsNot { found: '\'' })
+		);
+
+		let mut t = tokenizer("'single'").define_profile(LexerProfile::css());
+		assert_eq!(t.consume_string().unwrap().data, "single");
+
+		assert_eq!(
+			tokenizer("\"unterminated").consume_string(),
+			Err(StringParseError::Unterminated { line: 1, column: 1 })
+		);
+		let Err(StringParseError::UnescapedNewline { span }) =
+			tokenizer("\"bad\nstring\"").consume_string()
+		else {
+			panic!("saut de ligne non échappé attendu");
+		};
+		assert_eq!((span.start.line, span.start.column), (1, 1));
+		assert_eq!((span.end.line, span.end.column), (1, 4));
+		assert_eq!(
+			tokenizer("hello").consume_string(),
+			Err(StringParseError::IsNot { found: 'h' })
+		);
+	}
+
+	#[test]
+	fn test_profiles() {
+		let display = |profile: LexerProfile, source: &str| {
+			token_names(tokenizer(source).define_profile(profile)).unwrap()
+		};
+
+		assert_eq!(
+			display(LexerProfile::mikuscript(), "a -- b\nc"),
+			["identifier-a", "whitespace", "comment-line", "identifier-c"]
+		);
+		assert_eq!(
+			display(LexerProfile::css(), "a -- b"),
+			[
+				"identifier-a",
+				"whitespace",
+				"identifier---",
+				"whitespace",
+				"identifier-b",
+			]
+		);
+		assert_eq!(
+			display(LexerProfile::css(), "'str' 0x2A let"),
+			[
+				"string",
+				"whitespace",
+				"dimension-x2A",
+				"whitespace",
+				"identifier-let",
+			]
+		);
+		assert_eq!(
+			display(LexerProfile::css(), ">>="),
+			["greater-than", "greater-than", "equal"]
+		);
+		assert_eq!(
+			display(LexerProfile::html(), "<!-- a -- b -->"),
+			["comment-block"]
+		);
+		assert_eq!(
+			display(LexerProfile::msx(), "<!-- a --> /* b */"),
+			["comment-block", "whitespace", "comment-block"]
+		);
+
+		let mut t =
+			tokenizer("<!-- -- -->").define_profile(LexerProfile::html());
+		assert_eq!(t.consume_comments().unwrap().data, " -- ");
+
+		let mut t = tokenizer("// a").define_profile(LexerProfile::css());
+		assert_eq!(
+			StreamIterator::consume_next(&mut t).unwrap().to_string(),
+			"division"
+		);
+	}
+
+	#[test]
+	fn test_consume_string_escapes() {
+		let string = |source: &str| {
+			tokenizer(source).consume_string().map(|output| output.data)
+		};
+
+		assert_eq!(string(r#""a\n\t\r\\\"\'\0""#), Ok("a\n\t\r\\\"'\0".into()));
+		assert_eq!(string(r#""\x41\x7f""#), Ok("A\u{7f}".into()));
+		assert_eq!(string(r#""\u{1F600}\u{e9}""#), Ok("😀é".into()));
+		assert_eq!(string("\"un \\\n     deux\""), Ok("un deux".into()));
+		assert_eq!(string("\"a\\\r\n  b\""), Ok("ab".into()));
+		assert_eq!(string("\"a\\\r  b\""), Ok("ab".into()));
+
+		let output = tokenizer(r#""\u{e9}""#).consume_string().unwrap();
+		assert_eq!(output.raw, r"\u{e9}");
+
+		let error = |source: &str| {
+			let Err(error) = tokenizer(source).consume_string() else {
+				panic!("une erreur est attendue : {source}");
+			};
+			error
+		};
+		let span = |from: usize, to: usize| {
+			// Sur la première ligne, la colonne et le total sont égaux.
+			let mut start = Location::new();
+			(start.column, start.total) = (from, from);
+			let mut end = Location::new();
+			(end.column, end.total) = (to, to);
+			Span::new(start, end)
+		};
+
+		assert_eq!(
+			error(r#""ab\q""#),
+			StringParseError::InvalidEscape {
+				found: 'q',
+				span: span(4, 5)
+			}
+		);
+		assert_eq!(
+			error(r#""\x4""#),
+			StringParseError::InvalidEscape {
+				found: 'x',
+				span: span(2, 4)
+			}
+		);
+		assert_eq!(
+			error(r#""\u{12""#),
+			StringParseError::InvalidEscape {
+				found: 'u',
+				span: span(2, 6)
+			}
+		);
+		assert_eq!(
+			error(r#""\u{1234567}""#),
+			StringParseError::InvalidEscape {
+				found: 'u',
+				span: span(2, 11)
+			}
+		);
+		assert_eq!(
+			error(r#""\xFF""#),
+			StringParseError::OutOfRangeCodePoint {
+				value: 0xFF,
+				span: span(2, 5)
+			}
+		);
+		assert_eq!(
+			error(r#""\u{110000}""#),
+			StringParseError::OutOfRangeCodePoint {
+				value: 0x110000,
+				span: span(2, 11)
+			}
+		);
+		assert_eq!(
+			error(r#""\u{D800}""#),
+			StringParseError::LoneSurrogate {
+				value: 0xD800,
+				span: span(2, 9)
+			}
+		);
+
+		let mut t = tokenizer(r#""\q""#).define_profile(LexerProfile::css());
+		assert_eq!(t.consume_string().unwrap().data, r"\q");
+	}
+
+	#[test]
+	fn test_consume_raw_strings() {
+		let string = |source: &str| {
+			tokenizer(source).consume_string().map(|output| output.data)
+		};
+
+		assert_eq!(string(r#"r"C:\dossier\n""#), Ok(r"C:\dossier\n".into()));
+		assert_eq!(
+			string(r###"r#"un "guillemet" "# fin"#"###),
+			Ok(r#"un "guillemet" "#.into())
+		);
+		assert_eq!(string("r##\"a\"#\nb\"##"), Ok("a\"#\nb".into()));
+		assert_eq!(
+			string(r##"r#"non terminée""##),
+			Err(StringParseError::UnterminatedRaw {
+				hashes: 1,
+				line: 1,
+				column: 1
+			})
+		);
+
+		let mut t = tokenizer(r#"r"\d+" r#x"#);
+		let token = StreamIterator::consume_next(&mut t).unwrap();
+		let Token::Literal(Literal::String(output)) = token else {
+			panic!("une chaîne de caractères est attendue");
+		};
+		assert_eq!(
+			(output.data.as_str(), output.raw.as_str()),
+			(r"\d+", r"\d+")
+		);
+		assert_eq!(output.span.end.column, 6);
+
+		StreamIterator::consume_next(&mut t).unwrap();
+		let token = StreamIterator::consume_next(&mut t).unwrap();
+		assert_eq!(token.to_string(), "identifier-r");
+
+		let mut t = tokenizer(r#"r"a""#).define_profile(LexerProfile::mscss());
+		let token = StreamIterator::consume_next(&mut t).unwrap();
+		assert_eq!(token.to_string(), "identifier-r");
+	}
+
+	#[test]
+	fn test_template_strings() {
+		let display = |source: &str| token_names(tokenizer(source)).unwrap();
+
+		assert_eq!(display("`a\nb`"), ["template-no-substitution"]);
+		assert_eq!(
+			display("`a ${ {x: \"}\"} } c ${`${d}`} e`"),
+			[
+				"template-head",
+				"whitespace",
+				"left-curly-bracket",
+				"identifier-x",
+				"colon",
+				"whitespace",
+				"string",
+				"right-curly-bracket",
+				"whitespace",
+				"template-middle",
+				"template-head",
+				"identifier-d",
+				"template-tail",
+				"template-tail",
+			]
+		);
+
+		let mut t = tokenizer(r"`\${a}\`${b}!`");
+		let Token::Literal(Literal::Template(head)) =
+			StreamIterator::consume_next(&mut t).unwrap()
+		else {
+			panic!("un morceau de chaîne de caractères modèle est attendu");
+		};
+		assert_eq!(head.part, TemplatePart::Head);
+		assert_eq!(head.data, "${a}`");
+		assert_eq!(head.raw, r"\${a}\`");
+		assert_eq!((head.span.start.column, head.span.end.column), (1, 10));
+
+		StreamIterator::consume_next(&mut t).unwrap();
+		let tail = StreamIterator::consume_next(&mut t).unwrap();
+		assert_eq!(tail.span().start.column, 12);
+		assert_eq!(tail.to_string(), "template-tail");
+
+		let mut t = tokenizer("  `a ${b} c");
+		let errors: Vec<_> = (0..5)
+			.filter_map(|_| StreamIterator::consume_next(&mut t).err())
+			.collect();
+		assert_eq!(
+			errors,
+			[LexicalError::from(StringParseError::UnterminatedTemplate {
+				line: 1,
+				column: 3
+			})]
+		);
+
+		let mut t = tokenizer("`a`").define_profile(LexerProfile::mscss());
+		assert_eq!(
+			StreamIterator::consume_next(&mut t),
+			Err(LexicalError::from(DelimiterParseError::Invalid {
+				found: "`".into()
+			}))
+		);
+	}
+
+	#[test]
+	fn test_consume_char() {
+		let ch = |source: &str| {
+			tokenizer(source).consume_char().map(|output| output.value)
+		};
+
+		assert_eq!(ch("'C'"), Ok('C'));
+		assert_eq!(ch("'é'"), Ok('é'));
+		assert_eq!(ch(r"'\n'"), Ok('\n'));
+		assert_eq!(ch(r"'\''"), Ok('\''));
+		assert_eq!(ch(r"'\u{1F600}'"), Ok('😀'));
+		assert_eq!(ch("\"C\""), Err(CharParseError::IsNot { found: '"' }));
+
+		let span = |source: &str| match tokenizer(source).consume_char() {
+			| Err(
+				CharParseError::Empty { span }
+				| CharParseError::TooManyCharacters { span },
+			) => (span.start.column, span.end.column),
+			| result => panic!("une erreur est attendue : {result:?}"),
+		};
+		assert_eq!(span("''"), (1, 2));
+		assert_eq!(span("'ab' x"), (1, 4));
+		assert!(matches!(
+			tokenizer("'ab").consume_char(),
+			Err(CharParseError::Unterminated { line: 1, column: 1 })
+		));
+		assert!(matches!(
+			tokenizer("'a\n'").consume_char(),
+			Err(CharParseError::Unterminated { line: 1, column: 1 })
+		));
+		assert!(matches!(
+			tokenizer(r"'\q'").consume_char(),
+			Err(CharParseError::Escape(StringParseError::InvalidEscape {
+				found: 'q',
+				..
+			}))
+		));
+
+		let mut t = tokenizer("let ch = 'C';");
+		let tokens: Vec<_> = (0..7)
+			.map(|_| StreamIterator::consume_next(&mut t).unwrap().to_string())
+			.collect();
+		assert_eq!(tokens[6], "char");
+
+		let mut t = tokenizer("'C'").define_profile(LexerProfile::msx());
+		let token = StreamIterator::consume_next(&mut t).unwrap();
+		assert_eq!(token.to_string(), "string");
+	}
+
+	#[test]
+	fn test_consume_triple_quoted_strings() {
+		let string = |source: &str| {
+			tokenizer(source).consume_string().map(|output| output.data)
+		};
+
+		let source = "\"\"\"\n    SELECT *\r\n      FROM t\n\n    WHERE \
+		              \\\"a\\\"\n    \"\"\"";
+		assert_eq!(
+			string(source),
+			Ok("SELECT *\n  FROM t\n\nWHERE \"a\"".into())
+		);
+		assert_eq!(
+			string("\"\"\"un \"deux\" trois\"\"\""),
+			Ok("un \"deux\" trois".into())
+		);
+		assert_eq!(string("\"\"\"\n  a\n b\n  \"\"\""), Ok("a\n b".into()));
+		assert_eq!(string("\"\"\"\t\\t\"\"\""), Ok("\t\t".into()));
+
+		let mut t = tokenizer("x\n  \"\"\"\n  a\n  \"\"\" y");
+		t.consume_codepoint();
+		t.consume_codepoint();
+		t.consume_codepoint();
+		t.consume_codepoint();
+		let output = t.consume_string().unwrap();
+		assert_eq!(output.raw, "\n  a\n  ");
+		assert_eq!((output.span.start.line, output.span.start.column), (2, 3));
+		assert_eq!((output.span.end.line, output.span.end.column), (4, 5));
+
+		let mut t = tokenizer("\n \"\"\"\nnon terminée\n\"\"");
+		t.consume_codepoint();
+		t.consume_codepoint();
+		assert_eq!(
+			t.consume_string(),
+			Err(StringParseError::Unterminated { line: 2, column: 2 })
+		);
+
+		let mut t =
+			tokenizer("\"\"\"a\"\"\"").define_profile(LexerProfile::css());
+		assert_eq!(t.consume_string().unwrap().data, "");
+	}
+
+	#[test]
+	fn test_consume_byte_strings() {
+		let bytes = |source: &str| {
+			tokenizer(source)
+				.consume_byte_string()
+				.map(|output| output.value)
+		};
+
+		assert_eq!(bytes(r#"b"GET\r\n""#), Ok(b"GET\r\n".to_vec()));
+		assert_eq!(bytes(r#"b"\xFF\x00""#), Ok(vec![0xFF, 0x00]));
+		assert_eq!(bytes(r#"b"""#), Ok(vec![]));
+		assert!(matches!(
+			bytes(r#"b"café""#),
+			Err(ByteParseError::NonAscii { found: 'é', .. })
+		));
+		assert!(matches!(
+			bytes(r#"b"\u{41}""#),
+			Err(ByteParseError::Escape(StringParseError::InvalidEscape {
+				found: 'u',
+				..
+			}))
+		));
+		assert_eq!(
+			bytes(r#"b"abc"#),
+			Err(ByteParseError::Unterminated { line: 1, column: 1 })
+		);
+
+		let mut t = tokenizer(r#"b"a\x41""#);
+		let output = t.consume_byte_string().unwrap();
+		assert_eq!(output.raw, r"a\x41");
+		assert_eq!((output.span.start.column, output.span.end.column), (1, 8));
+
+		let byte =
+			|source: &str| tokenizer(source).consume_byte().map(|b| b.value);
+		assert_eq!(byte("b'x'"), Ok(b'x'));
+		assert_eq!(byte(r"b'\xFF'"), Ok(0xFF));
+		assert!(matches!(byte("b''"), Err(ByteParseError::Empty { .. })));
+		assert!(matches!(
+			byte("b'ab'"),
+			Err(ByteParseError::TooManyCharacters { .. })
+		));
+
+		// Les chaînes de caractères ordinaires restent limitées à `\x7F`.
+		assert!(matches!(
+			tokenizer(r#""\xFF""#).consume_string(),
+			Err(StringParseError::OutOfRangeCodePoint { value: 0xFF, .. })
+		));
+
+		let names = |t| token_names(t).unwrap();
+
+		assert_eq!(
+			names(tokenizer(r#"b'a' b"a" b"#)),
+			[
+				"byte",
+				"whitespace",
+				"byte-string",
+				"whitespace",
+				"identifier-b"
+			]
+		);
+		assert_eq!(
+			names(tokenizer(r#"b"a""#).define_profile(LexerProfile::css()))[0],
+			"identifier-b"
+		);
+	}
+
+	#[test]
+	fn test_consume_boolean() {
+		let mut t = tokenizer("true false");
+		assert_eq!(t.consume_boolean().map(|b| b.value), Some(Bool::True));
+		t.consume_codepoint();
+		assert_eq!(t.consume_boolean().map(|b| b.value), Some(Bool::False));
+		assert_eq!(t.peek_codepoint(), CodePoint::EOF);
+
+		let mut t = tokenizer("trueish");
+		assert_eq!(t.consume_boolean(), None);
+		assert_eq!(t.peek_codepoint(), CodePoint::Unit('t'));
+
+		let mut t = tokenizer("true").define_profile(LexerProfile::css());
+		assert_eq!(t.consume_boolean(), None);
+		assert_eq!(
+			token_names(
+				tokenizer("true-color false\\2d x")
+					.define_profile(LexerProfile::mscss())
+			)
+			.unwrap(),
+			["identifier-true-color", "whitespace", "identifier-false-x"]
+		);
+
+		// NOTE: hors syntaxe CSS, `-` n'appartient pas à l'identifiant.
+		let mut profile = LexerProfile::css();
+		profile.booleans = true;
+		let names =
+			token_names(tokenizer("true-color").define_profile(profile));
+		assert_eq!(names.unwrap(), ["identifier-true-color"]);
+		assert_eq!(
+			token_names(tokenizer("true-color")).unwrap(),
+			["bool", "subtraction", "identifier-color"]
+		);
+	}
+
+	#[test]
+	fn test_location() {
+		let mut t = tokenizer("a\r\nb\nc");
+		t.consume_codepoint();
+		t.consume_codepoint();
+		t.consume_codepoint();
+		assert_eq!(t.next_location().line, 2);
+		t.consume_codepoint();
+		t.consume_codepoint();
+		let location = t.next_location();
+		assert_eq!((location.line, location.column), (3, 1));
+	}
+
+	#[test]
+	fn test_tokens() {
+		let mut t = tokenizer("let x = 0x2A; // réponse\nx >>= 1..=2");
+		let mut kinds = vec![];
+		let mut display = vec![];
+		loop {
+			let token = StreamIterator::consume_next(&mut t).unwrap();
+			if token.is_eof() {
+				break;
+			}
+			kinds.push(*token.kind());
+			display.push(token.to_string());
+		}
+
+		assert_eq!(
+			display,
+			[
+				"keyword-let",
+				"whitespace",
+				"identifier-x",
+				"whitespace",
+				"equal",
+				"whitespace",
+				"numeric-hexadecimal",
+				"semicolon",
+				"whitespace",
+				"comment-line",
+				"identifier-x",
+				"whitespace",
+				"right-shift",
+				"whitespace",
+				"numeric-decimal",
+				"range-inclusive",
+				"numeric-decimal",
+			]
+		);
+		assert_eq!(kinds[9], TokenKind::Comment);
+	}
+
+	#[test]
+	fn test_token_span() {
+		let mut t = tokenizer("if\n  hello");
+		let keyword = StreamIterator::consume_next(&mut t).unwrap();
+		assert_eq!(keyword.span().start.column, 1);
+		assert_eq!(keyword.span().end.column, 2);
+
+		let newline = StreamIterator::consume_next(&mut t).unwrap();
+		assert!(matches!(newline, Token::Newline(_)));
+
+		StreamIterator::consume_next(&mut t).unwrap();
+		let ident = StreamIterator::consume_next(&mut t).unwrap();
+		assert_eq!(ident.span().start.line, 2);
+		assert_eq!(ident.span().start.column, 3);
+		assert_eq!(ident.span().end.column, 7);
+	}
+
+	#[test]
+	fn test_token_stream() {
+		let mut stream =
+			TokenStream::<Token, LexicalError>::from_stream(tokenizer("a + b"));
+		assert_eq!(stream.collect().len(), 5);
+
+		let token = stream.consume_next().unwrap();
+		assert_eq!(token.kind(), &TokenKind::Identifier);
+		assert_eq!(stream.peek_next().unwrap().kind(), &TokenKind::Whitespace);
+	}
+
+	#[test]
+	fn test_peek_and_reconsume() {
+		let mut t = tokenizer("a b");
+		let peeked = StreamIterator::peek_next(&mut t).unwrap();
+		let consumed = StreamIterator::consume_next(&mut t).unwrap();
+		assert_eq!(peeked, consumed);
+
+		t.reconsume_current();
+		assert_eq!(StreamIterator::consume_next(&mut t).unwrap(), consumed);
+		assert_eq!(
+			StreamIterator::consume_next(&mut t).unwrap().kind(),
+			&TokenKind::Whitespace
+		);
+	}
+
+	#[test]
+	fn test_consume_tokens_with_recovery() {
+		let source = "let s = \"oops\nlet \u{7} x = 1";
+		let recovered = tokenizer(source).consume_tokens_with_recovery();
+
+		assert_eq!(recovered.diagnostics.len(), 2);
+		let LexicalError::Literal(LiteralParseError::String(
+			StringParseError::UnescapedNewline { span },
+		)) = recovered.diagnostics[0].error
+		else {
+			panic!("saut de ligne non échappé attendu");
+		};
+		assert_eq!((span.start.column, span.end.column), (9, 13));
+		assert_eq!(recovered.diagnostics[0].span.start.column, 9);
+
+		let errors: Vec<_> = recovered
+			.tokens
+			.iter()
+			.filter_map(|token| token.as_ref().err())
+			.collect();
+		assert_eq!(errors[0].text, "\"oops");
+		assert_eq!(errors[1].text, "\u{7}");
+		assert_eq!(errors[1].span.start.line, 2);
+		assert_eq!(errors[1].span.start.column, 5);
+
+		let last = recovered.tokens.last().unwrap().as_ref().unwrap();
+		assert_eq!(last.to_string(), "numeric-decimal");
+	}
+
+	#[test]
+	fn test_char_recovery() {
+		let texts = |source: &str| {
+			tokenizer(source)
+				.consume_tokens_with_recovery()
+				.tokens
+				.into_iter()
+				.map(|token| match token {
+					| Ok(token) => token.to_string(),
+					| Err(error) => format!("ERR[{}]", error.text),
+				})
+				.collect::<Vec<_>>()
+		};
+
+		assert_eq!(
+			texts("'ab' ok"),
+			["ERR['ab']", "whitespace", "identifier-ok"]
+		);
+		assert_eq!(texts("'' x"), ["ERR['']", "whitespace", "identifier-x"]);
+		assert_eq!(
+			texts(r"'\q' y"),
+			[r"ERR['\q']", "whitespace", "identifier-y"]
+		);
+		assert_eq!(
+			texts(r"b'\u{1}' z"),
+			[r"ERR[b'\u{1}']", "whitespace", "identifier-z"]
+		);
+		assert_eq!(texts("'ab\nc"), ["ERR['ab]", "newline", "identifier-c"]);
+	}
+
+	#[test]
+	fn test_numeric_recovery() {
+		let texts = |source: &str| {
+			tokenizer(source)
+				.consume_tokens_with_recovery()
+				.tokens
+				.into_iter()
+				.map(|token| match token {
+					| Ok(token) => token.to_string(),
+					| Err(error) => format!("ERR[{}]", error.text),
+				})
+				.collect::<Vec<_>>()
+		};
+
+		assert_eq!(texts("08;"), ["ERR[08]", "semicolon"]);
+		assert_eq!(texts("1.2.3;"), ["ERR[1.2.3]", "semicolon"]);
+		assert_eq!(texts("0b1012"), ["ERR[0b1012]"]);
+		assert_eq!(
+			texts("0b12 x"),
+			["ERR[0b12]", "whitespace", "identifier-x"]
+		);
+		// NOTE: un point qui n'est pas suivi d'un chiffre n'appartient pas
+		// au nombre.
+		assert_eq!(texts("08..9"), ["ERR[08]", "range", "numeric-decimal"]);
+	}
+
+	#[test]
+	fn test_lookahead_recovery() {
+		let mut lexer = tokenizer("\"abc\nfoo bar");
+		let peeked = lexer.peek_n_next(3);
+		assert!(peeked[0].is_err());
+		assert_eq!(
+			peeked[2].as_ref().map(ToString::to_string),
+			Ok("identifier-foo".to_owned())
+		);
+
+		let output = lexer.consume_tokens_with_recovery();
+		let texts: Vec<_> = output
+			.tokens
+			.iter()
+			.map(|token| match token {
+				| Ok(token) => token.to_string(),
+				| Err(error) => format!("ERR[{}]", error.text),
+			})
+			.collect();
+		assert_eq!(
+			texts,
+			[
+				"ERR[\"abc]",
+				"newline",
+				"identifier-foo",
+				"whitespace",
+				"identifier-bar"
+			]
+		);
+
+		let Err(error) = &output.tokens[0] else {
+			panic!("le premier jeton doit être une erreur");
+		};
+		assert_eq!(error.span.start.line, 1);
+		assert_eq!(error.span.end.line, 1);
+	}
+}
@@ -4,5 +4,6 @@
 
 pub use codepoints;
 pub use lexer;
+pub use locale;
 pub use location;
 pub use stream;
@@ -0,0 +1,42 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+use crate::{interpolate, lookup, Locale, Message};
+
+// --------- //
+// Interface //
+// --------- //
+
+pub trait LocalizedMessage {
+	/// Le catalogue dans lequel chercher le message.
+	fn catalog(&self) -> &'static [Message];
+
+	/// Le code du message, autrement dit sa clé dans le
+	/// [catalogue](LocalizedMessage::catalog).
+	fn code(&self) -> &'static str;
+
+	/// Les valeurs à interpoler dans le message, par exemple le caractère
+	/// trouvé ou l'emplacement.
+	fn placeholders(&self) -> Vec<(&'static str, String)> {
+		Vec::new()
+	}
+
+	/// Le message dans une langue donnée.
+	///
+	/// Lorsque le code n'existe pas dans le catalogue, le code lui-même est
+	/// retourné.
+	fn localize(&self, locale: Locale) -> String {
+		match lookup(self.catalog(), self.code()) {
+			| Some(message) => {
+				interpolate(message.text(locale), &self.placeholders())
+			}
+			| None => self.code().to_owned(),
+		}
+	}
+
+	/// Le message dans la [langue actuelle](Locale::current).
+	fn message(&self) -> String {
+		self.localize(Locale::current())
+	}
+}
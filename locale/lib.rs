@@ -0,0 +1,243 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+mod interface;
+
+use core::{
+	fmt, str,
+	sync::atomic::{AtomicU8, Ordering},
+};
+
+pub use self::interface::LocalizedMessage;
+
+// -------- //
+// Constant //
+// -------- //
+
+/// La langue des messages, partagée par l'ensemble du programme.
+static CURRENT_LOCALE: AtomicU8 = AtomicU8::new(Locale::French as u8);
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Une entrée d'un catalogue de messages.
+///
+/// Les valeurs à interpoler sont écrites entre accolades, par exemple
+/// `{found}`, et sont remplacées par [interpolate].
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+pub struct Message {
+	/// Le code du message, autrement dit la clé du catalogue.
+	pub code: &'static str,
+
+	/// Le message en anglais.
+	pub en: &'static str,
+
+	/// Le message en français.
+	pub fr: &'static str,
+}
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+/// Les langues disponibles pour les messages.
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+#[repr(u8)]
+pub enum Locale {
+	/// Français.
+	#[default]
+	French,
+
+	/// Anglais.
+	English,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl Locale {
+	/// La langue actuellement utilisée pour les messages.
+	pub fn current() -> Self {
+		match CURRENT_LOCALE.load(Ordering::Relaxed) {
+			| n if n == Self::English as u8 => Self::English,
+			| _ => Self::French,
+		}
+	}
+
+	/// Défini la langue des messages pour l'ensemble du programme.
+	pub fn define(locale: Self) {
+		CURRENT_LOCALE.store(locale as u8, Ordering::Relaxed);
+	}
+
+	/// Déduit la langue à partir des variables d'environnement `LC_ALL`,
+	/// `LC_MESSAGES` puis `LANG`, dans cet ordre.
+	pub fn from_env() -> Option<Self> {
+		["LC_ALL", "LC_MESSAGES", "LANG"]
+			.into_iter()
+			.filter_map(|name| std::env::var(name).ok())
+			.find(|value| !value.is_empty())
+			.and_then(|value| value.parse().ok())
+	}
+}
+
+impl Message {
+	/// Le texte du message dans une langue donnée.
+	pub fn text(&self, locale: Locale) -> &'static str {
+		match locale {
+			| Locale::French => self.fr,
+			| Locale::English => self.en,
+		}
+	}
+}
+
+/// Cherche un message dans un catalogue à partir de son code.
+pub fn lookup(catalog: &[Message], code: &str) -> Option<Message> {
+	catalog.iter().find(|message| message.code == code).copied()
+}
+
+/// Remplace les valeurs à interpoler `{name}` d'un texte.
+///
+/// Une valeur qui n'est pas fournie est laissée telle quelle dans le texte.
+pub fn interpolate(template: &str, placeholders: &[(&str, String)]) -> String {
+	let mut output = String::with_capacity(template.len());
+	let mut rest = template;
+
+	while let Some(start) = rest.find('{') {
+		output.push_str(&rest[..start]);
+		rest = &rest[start..];
+
+		let Some(end) = rest.find('}') else {
+			break;
+		};
+
+		let name = &rest[1..end];
+		match placeholders.iter().find(|(key, _)| *key == name) {
+			| Some((_, value)) => output.push_str(value),
+			| None => output.push_str(&rest[..=end]),
+		}
+		rest = &rest[end + 1..];
+	}
+
+	output.push_str(rest);
+	output
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl fmt::Display for Locale {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"{}",
+			match self {
+				| Self::French => "fr",
+				| Self::English => "en",
+			}
+		)
+	}
+}
+
+impl str::FromStr for Locale {
+	type Err = &'static str;
+
+	// fr, fr-FR, fr_FR.UTF-8, french, en, en-US, english, ...
+	fn from_str(input: &str) -> Result<Self, Self::Err> {
+		let language = input
+			.split(['-', '_', '.'])
+			.next()
+			.unwrap_or_default()
+			.to_ascii_lowercase();
+
+		Ok(match language.as_ref() {
+			| "fr" | "french" | "francais" | "français" => Self::French,
+			| "en" | "english" | "anglais" => Self::English,
+			| _ => return Err("unsupported locale / langue non supportée"),
+		})
+	}
+}
+
+// ---- //
+// Test //
+// ---- //
+
+#[cfg(test)]
+mod tests {
+	use std::sync::{Mutex, MutexGuard, PoisonError};
+
+	use super::*;
+
+	/// Sérialise les tests qui modifient la langue globale.
+	static LOCALE_LOCK: Mutex<()> = Mutex::new(());
+
+	/// Verrouille la langue globale, et la restaure lorsqu'il est détruit.
+	struct LocaleGuard {
+		previous: Locale,
+		_lock: MutexGuard<'static, ()>,
+	}
+
+	impl LocaleGuard {
+		fn lock() -> Self {
+			let lock =
+				LOCALE_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+			Self {
+				previous: Locale::current(),
+				_lock: lock,
+			}
+		}
+	}
+
+	impl Drop for LocaleGuard {
+		fn drop(&mut self) {
+			Locale::define(self.previous);
+		}
+	}
+
+	#[test]
+	fn test_interpolate() {
+		assert_eq!(
+			interpolate(
+				"'{found}' at {line},{column}",
+				&[
+					("found", "@".to_owned()),
+					("line", 1.to_string()),
+					("column", 7.to_string()),
+				]
+			),
+			"'@' at 1,7"
+		);
+		assert_eq!(interpolate("{unknown} {", &[]), "{unknown} {");
+	}
+
+	#[test]
+	fn test_locale_from_str() {
+		assert_eq!("fr".parse(), Ok(Locale::French));
+		assert_eq!("fr_FR.UTF-8".parse(), Ok(Locale::French));
+		assert_eq!("en-US".parse(), Ok(Locale::English));
+		assert_eq!("English".parse(), Ok(Locale::English));
+		assert!("de".parse::<Locale>().is_err());
+	}
+
+	#[test]
+	fn test_locale_define() {
+		let guard = LocaleGuard::lock();
+		let previous = guard.previous;
+
+		Locale::define(Locale::English);
+		assert_eq!(Locale::current(), Locale::English);
+		Locale::define(Locale::French);
+		assert_eq!(Locale::current(), Locale::French);
+
+		drop(guard);
+		assert_eq!(Locale::current(), previous);
+	}
+}
@@ -0,0 +1,62 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+use core::fmt;
+
+use locale::{LocalizedMessage, Message};
+
+// -------- //
+// Constant //
+// -------- //
+
+const MESSAGES: &[Message] = &[
+	Message {
+		code: "location-invalid-line",
+		en: "This is not a valid line.",
+		fr: "Il ne s'agit pas d'une ligne valide.",
+	},
+	Message {
+		code: "location-invalid-column",
+		en: "This is not a valid column.",
+		fr: "Il ne s'agit pas d'une colonne valide.",
+	},
+];
+
+// ----------- //
+// Énumération //
+// ----------- //
+
+#[derive(Debug)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+pub enum LocationParseError {
+	/// La ligne n'est pas un nombre valide.
+	InvalidLine,
+
+	/// La colonne n'est pas un nombre valide.
+	InvalidColumn,
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl LocalizedMessage for LocationParseError {
+	fn catalog(&self) -> &'static [Message] {
+		MESSAGES
+	}
+
+	fn code(&self) -> &'static str {
+		match self {
+			| Self::InvalidLine => "location-invalid-line",
+			| Self::InvalidColumn => "location-invalid-column",
+		}
+	}
+}
+
+impl fmt::Display for LocationParseError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.message())
+	}
+}
@@ -2,11 +2,15 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
 
+mod error;
 mod interface;
+mod span;
 
 use core::{fmt, str};
 
-pub use self::interface::LocationInterface;
+pub use self::{
+	error::LocationParseError, interface::LocationInterface, span::Span,
+};
 
 // --------- //
 // Structure //
@@ -84,7 +88,7 @@ impl fmt::Display for Location {
 }
 
 impl str::FromStr for Location {
-	type Err = &'static str;
+	type Err = LocationParseError;
 
 	// L:{0..9},C:{0..9}
 	fn from_str(location_str: &str) -> Result<Self, Self::Err> {
@@ -114,12 +118,11 @@ impl str::FromStr for Location {
 			column.push(ch);
 		}
 
-		let line: usize = line
-			.parse()
-			.map_err(|_| "Il ne s'agit pas d'une ligne valide.")?;
+		let line: usize =
+			line.parse().map_err(|_| LocationParseError::InvalidLine)?;
 		let column: usize = column
 			.parse()
-			.map_err(|_| "Il ne s'agit pas d'une colonne valide.")?;
+			.map_err(|_| LocationParseError::InvalidColumn)?;
 
 		Ok(Self {
 			line,
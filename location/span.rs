@@ -0,0 +1,52 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+use core::fmt;
+
+use crate::Location;
+
+// --------- //
+// Structure //
+// --------- //
+
+/// Une portion du code source, comprise entre deux emplacements.
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(Copy, Clone)]
+#[derive(PartialEq, Eq)]
+pub struct Span {
+	/// L'emplacement du premier point de code de la portion.
+	pub start: Location,
+
+	/// L'emplacement du dernier point de code de la portion.
+	pub end: Location,
+}
+
+// -------------- //
+// Implémentation //
+// -------------- //
+
+impl Span {
+	/// Crée une portion à partir de deux emplacements.
+	pub fn new(start: Location, end: Location) -> Self {
+		Self { start, end }
+	}
+}
+
+// -------------- //
+// Implémentation // -> Interface
+// -------------- //
+
+impl From<Location> for Span {
+	/// Portion ne contenant qu'un seul emplacement.
+	fn from(location: Location) -> Self {
+		Self::new(location, location)
+	}
+}
+
+impl fmt::Display for Span {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}..{}", self.start, self.end)
+	}
+}
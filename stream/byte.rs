@@ -40,7 +40,7 @@ impl ByteStream {
 	}
 
 	/// Liste des caractères de la chaîne de caractères.
-	pub fn chars(&self) -> str::Chars {
+	pub fn chars(&self) -> str::Chars<'_> {
 		self.buffer.chars()
 	}
 }
@@ -59,10 +59,9 @@ impl fmt::Display for ByteStream {
 // Implémentation // -> From<T>
 // -------------- //
 
-/// Crée un nouveau [ByteStream] à partir d'octets (provenant
-/// du réseau/fichier).
-
 impl<const N: usize> From<&[u8; N]> for ByteStream {
+	/// Crée un nouveau [ByteStream] à partir d'octets (provenant
+	/// du réseau/fichier).
 	fn from(buf_bytes: &[u8; N]) -> Self {
 		let decoded_data = str::from_utf8(buf_bytes)
 			.map(|data| data.to_owned())
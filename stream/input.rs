@@ -294,6 +294,16 @@ mod tests {
 		assert_eq!(input_stream.consume_next(), Ok(CodePoint::EOF));
 	}
 
+	#[test]
+	fn test_peek_n_next_at_end_of_stream() {
+		let mut input_stream = InputStream::new("ab".chars());
+		assert_eq!(
+			input_stream.peek_n_next(4),
+			vec![Ok(CodePoint::Unit('a')), Ok(CodePoint::Unit('b'))]
+		);
+		assert_eq!(input_stream.consume_next(), Ok(CodePoint::Unit('a')));
+	}
+
 	#[test]
 	fn test_advance_as_long_as_possible() {
 		let source = format!("     {}", SOURCE);
@@ -83,11 +83,15 @@ where
 			.map(|items| items.iter().map(|mch| mch.to_owned()).collect::<R>())
 	}
 
+	/// Les éléments d'une plage, sans les consommer. Lorsque la source se
+	/// termine avant la fin de la plage, seuls les éléments disponibles sont
+	/// retournés.
 	fn peek_next_range(&mut self, range: Range<usize>) -> Option<&[I]> {
 		if range.end > self.temporary_list.len() {
 			self.fill(range.end);
 		}
-		self.temporary_list.get(range)
+		let end = range.end.min(self.temporary_list.len());
+		self.temporary_list.get(range.start..end)
 	}
 }
 